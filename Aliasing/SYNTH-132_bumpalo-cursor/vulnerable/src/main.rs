@@ -0,0 +1,94 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk.
+// `used` is the bump cursor: the offset of the first byte no allocation owns yet.
+pub struct Bump {
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            chunk,
+            used: Cell::new(0),
+        }
+    }
+
+    // `alloc` takes `&self` but returns `&mut T`. That is only sound because every
+    // call is handed a region no other allocation overlaps.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+impl Bump {
+    // Rounds the cursor up to the alignment, but then stores the *start* of the new
+    // allocation back as the cursor instead of its end. The cursor never moves past
+    // the last allocation, so the next call of the same alignment is handed the very
+    // same bytes, and the two `&mut` references returned by `alloc` alias.
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => {}
+            _ => handle_alloc_error(layout),
+        }
+        self.used.set(start);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup two separate allocations with distinct values.
+    let bump = Bump::new();
+    let first = bump.alloc(0x1111_1111_1111_1111u64);
+    let second = bump.alloc(0x2222_2222_2222_2222u64);
+    println!("first at {:p}, second at {:p}", first as *mut u64, second as *mut u64);
+
+    // 2. Trigger BUG: write through the first reference only.
+    *first = 0x4141_4141_4141_4141;
+
+    // 3. Verify that the second allocation is untouched. If both references cover
+    // the same bytes, the write above is visible through `second`.
+    println!("second = {:#018x}", *second);
+    assert_eq!(*second, 0x2222_2222_2222_2222, "ALIASING CONFIRMED: two live &mut point to the same allocation!");
+}
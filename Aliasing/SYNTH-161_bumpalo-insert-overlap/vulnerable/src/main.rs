@@ -0,0 +1,202 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, self.cap);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+    #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+
+// SECTION 2: VULNERABLE CODE
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    // Inserts `element` at `index`, shifting every later element one slot to the
+    // right. The shift moves `len - index` elements from `index` to `index + 1`
+    // inside the same buffer, so the source and destination ranges overlap
+    // whenever more than one element is moved. `copy_nonoverlapping` promises the
+    // compiler that they don't, which makes this call undefined behavior.
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len;
+        assert!(index <= len, "insertion index (is {}) should be <= len (is {})", index, len);
+        if len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let p = self.buf.ptr().add(index);
+            ptr::copy_nonoverlapping(p, p.add(1), len - index);
+            ptr::write(p, element);
+            self.len = len + 1;
+        }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup a vector of sixteen distinct values with room for a seventeenth.
+    let bump = Bump::new();
+    let mut vec = Vec::new_in(&bump);
+    for i in 1..=16u64 {
+        vec.push(i * 0x0101_0101);
+    }
+    vec.reserve(1);
+    assert_eq!(vec.len(), 16);
+
+    // 2. Trigger BUG: insert in the middle. Fifteen elements (120 bytes) are shifted
+    // within the buffer over a range that overlaps itself by all but one slot. The
+    // length check above lets the compiler see the copy size, so a size-optimized
+    // release build emits the copy inline as a forward `rep movsb`, which re-reads
+    // bytes it has already overwritten. In a debug build the standard library's
+    // precondition check aborts right here with
+    // "ptr::copy_nonoverlapping requires ... the specified memory ranges do not overlap".
+    vec.insert(1, 0xAAAA_AAAA);
+
+    // 3. Verify the order. Every original element must have moved up by exactly one.
+    let mut expected: std::vec::Vec<u64> = (1..=16).map(|i| i * 0x0101_0101).collect();
+    expected.insert(1, 0xAAAA_AAAA);
+    println!("Vector after insert: {:08x?}", vec.as_slice());
+    assert_eq!(vec.as_slice(), &expected[..], "OVERLAPPING COPY CONFIRMED: the shifted elements were corrupted!");
+}
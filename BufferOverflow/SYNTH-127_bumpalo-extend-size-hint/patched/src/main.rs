@@ -0,0 +1,228 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk,
+// in address order. As in bumpalo, two allocations made back to back are
+// neighbours in memory, so writing past the end of one lands in the next.
+pub struct Bump {
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            chunk,
+            used: Cell::new(0),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        let end = match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => end,
+            _ => handle_alloc_error(layout),
+        };
+        self.used.set(end);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, self.cap);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+    #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+// An iterator that under-reports its length: `size_hint` claims it is empty,
+// but it yields `remaining` items. Implementing `Iterator` is safe, so unsafe code
+// must not rely on the hint being accurate.
+pub struct LyingIter {
+    remaining: usize,
+    value: u32,
+}
+
+impl LyingIter {
+    pub fn new(remaining: usize, value: u32) -> Self {
+        LyingIter { remaining, value }
+    }
+}
+
+impl Iterator for LyingIter {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.remaining == 0 {
+            None
+        } else {
+            self.remaining -= 1;
+            Some(self.value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+// `extend` only uses the lower bound as a reservation hint and goes through `push`
+// for every item, so the capacity is checked again before each write.
+impl<'bump, T: 'bump> Extend<T> for Vec<'bump, T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for t in iter {
+            self.push(t);
+        }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup a full vector (8 of 8 slots used) and a canary allocated after it.
+    let bump = Bump::new();
+    let mut vec = Vec::new_in(&bump);
+    vec.extend([0x1111_1111u32; 8]);
+    let canary = bump.alloc([0xCCCC_CCCCu32; 16]);
+
+    // 2. Extend with an iterator that claims to be empty but yields 24 items.
+    vec.extend(LyingIter::new(24, 0x4141_4141));
+
+    // 3. Verify the canary. Its bytes must be untouched by the vector's writes.
+    println!("Vector length: {}, capacity: {}", vec.len(), vec.capacity());
+    println!("Canary: {:08x?}", &canary[..]);
+    assert!(canary.iter().all(|&c| c == 0xCCCC_CCCC));
+    assert_eq!(vec.len(), 32);
+    println!("Verification successful: the vector grew instead of overflowing.");
+}
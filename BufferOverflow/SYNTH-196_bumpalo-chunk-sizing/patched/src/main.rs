@@ -0,0 +1,134 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::{Cell, RefCell};
+use std::ptr::{self, NonNull};
+use std::panic::{self, AssertUnwindSafe};
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+fn alloc_chunk(size: usize) -> NonNull<u8> {
+    let layout = Layout::from_size_align(size, CHUNK_ALIGN).unwrap_or_else(|_| capacity_overflow());
+    match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+        Some(p) => p,
+        None => handle_alloc_error(layout),
+    }
+}
+
+// Simplified Bump allocator that carves allocations out of a list of chunks, like
+// bumpalo. Allocations come from the newest chunk. When a request does not fit, a
+// new chunk is allocated, sized by `new_chunk_size` to hold the request, and the
+// request is placed at its start.
+pub struct Bump {
+    // Every chunk, oldest first, as (start, size). The last one is the current chunk.
+    chunks: RefCell<std::vec::Vec<(NonNull<u8>, usize)>>,
+    // Offset of the first byte of the current chunk no allocation owns yet.
+    used: Cell<usize>,
+}
+
+const MIN_CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            chunks: RefCell::new(vec![(alloc_chunk(MIN_CHUNK_SIZE), MIN_CHUNK_SIZE)]),
+            used: Cell::new(0),
+        }
+    }
+
+    // `alloc` takes `&self` but returns `&mut T`. That is only sound because every
+    // call is handed a region no other allocation overlaps.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+
+    pub fn current_chunk_size(&self) -> usize {
+        self.chunks.borrow().last().unwrap().1
+    }
+
+    pub fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let (chunk, size) = *self.chunks.borrow().last().unwrap();
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        match start.checked_add(layout.size()) {
+            Some(end) if end <= size => {
+                self.used.set(end);
+                unsafe { NonNull::new_unchecked(chunk.as_ptr().add(start)) }
+            }
+            _ => {
+                // Chunks are aligned to `CHUNK_ALIGN`, so the request fits at the start of
+                // the new chunk without padding; no bounds check is repeated here.
+                let new_size = self.new_chunk_size(layout);
+                let chunk = alloc_chunk(new_size);
+                self.chunks.borrow_mut().push((chunk, new_size));
+                self.used.set(layout.size());
+                chunk
+            }
+        }
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for &(chunk, size) in self.chunks.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(chunk.as_ptr(), Layout::from_size_align_unchecked(size, CHUNK_ALIGN));
+            }
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+impl Bump {
+    // Size of the chunk that `alloc_layout` places `layout` in: double the current
+    // chunk, or the request plus `CHUNK_ALIGN` bytes of slack rounded up to a power
+    // of two, whichever is larger. Every step is checked, and a size that does not
+    // fit in a `usize` is reported as an allocation error instead of wrapping.
+    fn new_chunk_size(&self, layout: Layout) -> usize {
+        let required = layout.size().checked_add(CHUNK_ALIGN).and_then(usize::checked_next_power_of_two);
+        let doubled = self.current_chunk_size().checked_mul(2);
+        match (required, doubled) {
+            (Some(required), Some(doubled)) => required.max(doubled),
+            _ => handle_alloc_error(layout),
+        }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+// 2^63 - 8: a valid layout size, but adding the slack pushes it past 2^63, and the
+// next power of two, 2^64, does not fit in a `usize`.
+const HUGE: usize = usize::MAX / 2 - 7;
+
+fn main() {
+    // 1. Setup an arena and a request that does not fit in its first chunk.
+    let bump = Bump::new();
+    let layout = Layout::from_size_align(HUGE, 8).unwrap();
+
+    // 2. Allocate the block. Sizing its chunk overflows, so the request is rejected.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| bump.alloc_layout(layout)));
+    println!("Oversized request rejected: {}", result.is_err());
+    assert!(result.is_err(), "HEAP OVERFLOW CONFIRMED: the block extends past the end of its chunk!");
+
+    // 3. Requests that do fit in a `usize` still grow the arena as before.
+    bump.alloc([0u8; 10_000]);
+    println!("Chunk after a 10000-byte allocation: {} bytes", bump.current_chunk_size());
+    assert_eq!(bump.current_chunk_size(), 16384);
+    println!("Verification successful: chunk sizing rejects sizes that overflow.");
+}
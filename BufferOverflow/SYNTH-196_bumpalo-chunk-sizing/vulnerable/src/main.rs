@@ -0,0 +1,129 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::{Cell, RefCell};
+use std::ptr::{self, NonNull};
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+fn alloc_chunk(size: usize) -> NonNull<u8> {
+    let layout = Layout::from_size_align(size, CHUNK_ALIGN).unwrap_or_else(|_| capacity_overflow());
+    match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+        Some(p) => p,
+        None => handle_alloc_error(layout),
+    }
+}
+
+// Simplified Bump allocator that carves allocations out of a list of chunks, like
+// bumpalo. Allocations come from the newest chunk. When a request does not fit, a
+// new chunk is allocated, sized by `new_chunk_size` to hold the request, and the
+// request is placed at its start.
+pub struct Bump {
+    // Every chunk, oldest first, as (start, size). The last one is the current chunk.
+    chunks: RefCell<std::vec::Vec<(NonNull<u8>, usize)>>,
+    // Offset of the first byte of the current chunk no allocation owns yet.
+    used: Cell<usize>,
+}
+
+const MIN_CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            chunks: RefCell::new(vec![(alloc_chunk(MIN_CHUNK_SIZE), MIN_CHUNK_SIZE)]),
+            used: Cell::new(0),
+        }
+    }
+
+    // `alloc` takes `&self` but returns `&mut T`. That is only sound because every
+    // call is handed a region no other allocation overlaps.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+
+    pub fn current_chunk_size(&self) -> usize {
+        self.chunks.borrow().last().unwrap().1
+    }
+
+    pub fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let (chunk, size) = *self.chunks.borrow().last().unwrap();
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        match start.checked_add(layout.size()) {
+            Some(end) if end <= size => {
+                self.used.set(end);
+                unsafe { NonNull::new_unchecked(chunk.as_ptr().add(start)) }
+            }
+            _ => {
+                // Chunks are aligned to `CHUNK_ALIGN`, so the request fits at the start of
+                // the new chunk without padding; no bounds check is repeated here.
+                let new_size = self.new_chunk_size(layout);
+                let chunk = alloc_chunk(new_size);
+                self.chunks.borrow_mut().push((chunk, new_size));
+                self.used.set(layout.size());
+                chunk
+            }
+        }
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for &(chunk, size) in self.chunks.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(chunk.as_ptr(), Layout::from_size_align_unchecked(size, CHUNK_ALIGN));
+            }
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+impl Bump {
+    // Size of the chunk that `alloc_layout` places `layout` in: double the current
+    // chunk, or the request plus `CHUNK_ALIGN` bytes of slack rounded up to a power
+    // of two, whichever is larger. `next_power_of_two` wraps to 0 when the result
+    // does not fit in a `usize`, which it does without overflow checks, as in a
+    // release build. A request just under half the address space then gets a chunk
+    // of twice the current size, a few kilobytes, and is placed in it whole.
+    fn new_chunk_size(&self, layout: Layout) -> usize {
+        let required = (layout.size() + CHUNK_ALIGN).next_power_of_two();
+        required.max(self.current_chunk_size() * 2)
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+// 2^63 - 8: a valid layout size, but adding the slack pushes it past 2^63, and the
+// next power of two, 2^64, does not fit in a `usize`.
+const HUGE: usize = usize::MAX / 2 - 7;
+
+fn main() {
+    // 1. Setup an arena and a request that does not fit in its first chunk.
+    let bump = Bump::new();
+    let layout = Layout::from_size_align(HUGE, 8).unwrap();
+
+    // 2. TRIGGER: allocate the block. The arena starts a new chunk for it.
+    let block = bump.alloc_layout(layout);
+
+    // 3. Verify the block fits in the chunk it was placed at the start of. Every byte
+    // of the block past the chunk's end belongs to other heap memory.
+    println!("Block at {:p}: {:#x} bytes requested", block, HUGE);
+    println!("Chunk holding the block: {} bytes", bump.current_chunk_size());
+    assert!(HUGE <= bump.current_chunk_size(), "HEAP OVERFLOW CONFIRMED: the block extends past the end of its chunk!");
+}
@@ -0,0 +1,213 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr::{self, NonNull};
+use std::slice;
+use std::panic::{self, AssertUnwindSafe};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk,
+// in address order. As in bumpalo, two allocations made back to back are
+// neighbours in memory, so writing past the end of one lands in the next.
+pub struct Bump {
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            chunk,
+            used: Cell::new(0),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        let end = match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => end,
+            _ => handle_alloc_error(layout),
+        };
+        self.used.set(end);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, self.cap);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+    #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    // Concatenates `slices` into a new arena vector, allocating the buffer once. The
+    // total length is summed with `checked_add`, and a total that does not fit in a
+    // `usize` is reported as a capacity overflow before anything is allocated.
+    pub fn from_slices_in(bump: &'bump Bump, slices: &[&[T]]) -> Vec<'bump, T>
+    where
+        T: Copy,
+    {
+        let total = slices
+            .iter()
+            .try_fold(0usize, |total, s| total.checked_add(s.len()))
+            .unwrap_or_else(|| capacity_overflow());
+        let mut vec: Vec<T> = Vec::new_in(bump);
+        vec.reserve(total);
+        for s in slices {
+            unsafe {
+                ptr::copy_nonoverlapping(s.as_ptr(), vec.buf.ptr().add(vec.len), s.len());
+            }
+            vec.len += s.len();
+        }
+        vec
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup: slices of a zero-sized type take no memory at any length, so their
+    // lengths can add up to more than `usize::MAX`.
+    let bump = Bump::new();
+    let units = [(); 3];
+    // SAFETY: a slice of a zero-sized type may have any length; it reads no memory.
+    let huge: &[()] = unsafe { slice::from_raw_parts(NonNull::dangling().as_ptr(), usize::MAX - 1) };
+    let slices = [huge, &units[..]];
+
+    // 2. Concatenate them. The summed length overflows, so the call panics.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| Vec::from_slices_in(&bump, &slices).len()));
+    println!("Overflowing concatenation rejected: {}", result.is_err());
+    assert!(result.is_err(), "INTEGER OVERFLOW CONFIRMED: the summed length wrapped around!");
+
+    // 3. Slices whose lengths fit are still joined in order.
+    let joined = Vec::from_slices_in(&bump, &[&b"GET "[..], b"/index", b".html"]);
+    println!("Joined: {:?}", String::from_utf8_lossy(joined.as_slice()));
+    assert_eq!(joined.as_slice(), b"GET /index.html");
+    println!("Verification successful: the summed length is checked for overflow.");
+}
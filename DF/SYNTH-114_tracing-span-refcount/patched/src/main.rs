@@ -0,0 +1,91 @@
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Number of times a span's `Inner` has been freed.
+static INNER_FREES: AtomicUsize = AtomicUsize::new(0);
+
+// Minimal definition for `tracing::span::Inner`
+// The real `Inner` is shared between all clones of a span and is released when the
+// last clone closes it. Here the sharing is modeled with a manual reference count.
+#[derive(Debug)]
+pub struct Inner {
+    refs: Cell<usize>,
+    id: u64,
+}
+
+// Minimal definition for `tracing::Span`
+#[derive(Debug)]
+pub struct Span {
+    inner: NonNull<Inner>,
+}
+
+impl Span {
+    pub fn new(id: u64) -> Self {
+        let inner = Box::new(Inner {
+            refs: Cell::new(1),
+            id,
+        });
+        Self {
+            inner: NonNull::from(Box::leak(inner)),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        unsafe { self.inner.as_ref().id }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        unsafe {
+            let refs = &self.inner.as_ref().refs;
+            refs.set(refs.get() - 1);
+            if refs.get() == 0 {
+                drop(Box::from_raw(self.inner.as_ptr()));
+                INNER_FREES.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+}
+
+
+// SECTION 2: PATCHED CODE
+
+// `Clone` takes a new reference on the shared `Inner`, so it is freed only after
+// every clone has been dropped.
+impl Clone for Span {
+    fn clone(&self) -> Self {
+        let refs = unsafe { &self.inner.as_ref().refs };
+        refs.set(refs.get() + 1);
+        Self { inner: self.inner }
+    }
+}
+
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup a span and a clone of it.
+    let span_a = Span::new(1);
+    let stale = span_a.clone();
+
+    // 2. Drop the original. `stale` still holds a reference, so `Inner` stays alive.
+    drop(span_a);
+
+    // 3. Create an unrelated span. Its `Inner` gets a block of its own.
+    let span_b = Span::new(2);
+
+    // 4. Drop the clone. It releases the last reference to the first `Inner`.
+    drop(stale);
+
+    // 5. Verify the result. Exactly one `Inner` was freed and `span_b` is intact.
+    let frees = INNER_FREES.load(Ordering::SeqCst);
+    println!("Inner frees:  {}", frees);
+    println!("span_b id:    {}", span_b.id());
+    assert_eq!(frees, 1);
+    assert_eq!(span_b.id(), 2);
+    println!("Verification successful: each Inner was freed exactly once.");
+}
@@ -0,0 +1,91 @@
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::cell::Cell;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Number of times a span's `Inner` has been freed.
+static INNER_FREES: AtomicUsize = AtomicUsize::new(0);
+
+// Minimal definition for `tracing::span::Inner`
+// The real `Inner` is shared between all clones of a span and is released when the
+// last clone closes it. Here the sharing is modeled with a manual reference count.
+#[derive(Debug)]
+pub struct Inner {
+    refs: Cell<usize>,
+    id: u64,
+}
+
+// Minimal definition for `tracing::Span`
+#[derive(Debug)]
+pub struct Span {
+    inner: NonNull<Inner>,
+}
+
+impl Span {
+    pub fn new(id: u64) -> Self {
+        let inner = Box::new(Inner {
+            refs: Cell::new(1),
+            id,
+        });
+        Self {
+            inner: NonNull::from(Box::leak(inner)),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        unsafe { self.inner.as_ref().id }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        unsafe {
+            let refs = &self.inner.as_ref().refs;
+            refs.set(refs.get() - 1);
+            if refs.get() == 0 {
+                drop(Box::from_raw(self.inner.as_ptr()));
+                INNER_FREES.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+}
+
+
+// SECTION 2: VULNERABLE CODE
+
+// `Clone` copies the raw `inner` pointer but does not take a new reference. Two
+// spans now share one `Inner` with a count of 1, so the first drop frees it and
+// the second drop releases it again.
+impl Clone for Span {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner }
+    }
+}
+
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup a span and a clone of it.
+    let span_a = Span::new(1);
+    let stale = span_a.clone();
+
+    // 2. Drop the original. With a correct count, `stale` would keep `Inner` alive.
+    drop(span_a);
+
+    // 3. Create an unrelated span. Its `Inner` reuses the block that was just freed.
+    let span_b = Span::new(2);
+
+    // 4. Trigger BUG: drop the clone. It releases `Inner` a second time, which now
+    // belongs to `span_b`, and frees it while `span_b` is still alive.
+    drop(stale);
+
+    // 5. Verify the double release. Only `span_a` has been dropped, so exactly one
+    // `Inner` should have been freed, and `span_b` should still report id 2.
+    let frees = INNER_FREES.load(Ordering::SeqCst);
+    println!("Inner frees:  {}", frees);
+    println!("span_b id:    {}", span_b.id());
+    assert_eq!(frees, 1, "DOUBLE FREE CONFIRMED: the shared Inner was released twice!");
+    assert_eq!(span_b.id(), 2);
+}
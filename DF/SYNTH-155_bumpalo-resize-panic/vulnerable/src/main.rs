@@ -0,0 +1,267 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, self.cap);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+    #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    // Shortens the vector to `len` elements, dropping the rest. The dropped elements'
+    // bytes stay in the buffer until they are overwritten.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            unsafe {
+                let tail = ptr::slice_from_raw_parts_mut(self.buf.ptr().add(len), self.len - len);
+                self.len = len;
+                ptr::drop_in_place(tail);
+            }
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+// Like the crate's `Vec`, dropping the vector drops its elements in place.
+impl<'bump, T: 'bump> Drop for Vec<'bump, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.buf.ptr(), self.len));
+        }
+    }
+}
+
+// How many times a `Token` with each id has been dropped.
+const MAX_ID: usize = 16;
+static DROPS: [AtomicUsize; MAX_ID] = [const { AtomicUsize::new(0) }; MAX_ID];
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+pub fn drops_of(id: usize) -> usize {
+    DROPS[id].load(Ordering::SeqCst)
+}
+
+// An element whose `clone` panics on the third call, standing in for any `Clone`
+// impl that can fail (an allocation, a `RefCell` borrow, a user callback).
+pub struct Token {
+    id: usize,
+}
+
+impl Token {
+    pub fn new(id: usize) -> Self {
+        assert!(id < MAX_ID);
+        Token { id }
+    }
+}
+
+impl Clone for Token {
+    fn clone(&self) -> Self {
+        if CLONES.fetch_add(1, Ordering::SeqCst) + 1 == 3 {
+            panic!("Token::clone failed");
+        }
+        Token { id: self.id }
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        if self.id < MAX_ID {
+            DROPS[self.id].fetch_add(1, Ordering::SeqCst);
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    // Grows the vector to `new_len` with clones of `value`. The new length is stored
+    // before any clone is written. If a `clone` panics, the vector already counts the
+    // slots that were never written, and they still hold the bytes of elements that
+    // `truncate` dropped earlier. Dropping the vector drops those elements again.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let len = self.len;
+        if new_len > len {
+            let extra = new_len - len;
+            self.reserve(extra);
+            unsafe {
+                let mut dst = self.buf.ptr().add(len);
+                self.len = new_len;
+                for _ in 1..extra {
+                    ptr::write(dst, value.clone());
+                    dst = dst.add(1);
+                }
+                ptr::write(dst, value);
+            }
+        } else {
+            self.truncate(new_len);
+        }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup a vector of tokens 1..=5, then truncate it back to one element. The
+    // buffer still holds the bytes of the dropped tokens 2..=5.
+    let bump = Bump::new();
+    let mut vec = Vec::new_in(&bump);
+    for id in 1..=5 {
+        vec.push(Token::new(id));
+    }
+    vec.truncate(1);
+
+    // 2. Trigger BUG: grow back to 5 with clones of token 9. The third clone panics.
+    let result = panic::catch_unwind(AssertUnwindSafe(|| vec.resize(5, Token::new(9))));
+    println!("resize panicked: {}, length afterwards: {}", result.is_err(), vec.len());
+
+    // 3. Drop the vector, dropping every element it counts.
+    drop(vec);
+
+    // 4. Verify each original token was dropped exactly once.
+    let drops: std::vec::Vec<usize> = (1..=5).map(drops_of).collect();
+    println!("Drops of tokens 1..=5: {:?}", drops);
+    assert_eq!(drops, [1, 1, 1, 1, 1], "DOUBLE DROP CONFIRMED: stale elements were dropped again!");
+}
@@ -0,0 +1,291 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, self.cap);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+// Like the crate's `Vec`, dropping the vector drops its elements in place.
+impl<'bump, T: 'bump> Drop for Vec<'bump, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.buf.ptr(), self.len));
+        }
+    }
+}
+
+// Minimal definition of the crate's `vec::IntoIter`. Like the crate's iterator, it
+// owns the elements it has not yielded yet and drops them when it is dropped.
+pub struct IntoIter<'bump, T> {
+    phantom: PhantomData<&'bump [T]>,
+    ptr: *const T,
+    end: *const T,
+}
+
+impl<'bump, T: 'bump> IntoIterator for Vec<'bump, T> {
+    type Item = T;
+    type IntoIter = IntoIter<'bump, T>;
+
+    #[inline]
+    fn into_iter(mut self) -> IntoIter<'bump, T> {
+        assert!(mem::size_of::<T>() != 0, "zero-sized elements are not modeled");
+        unsafe {
+            let begin = self.as_mut_ptr();
+            let end = begin.add(self.len());
+            mem::forget(self);
+            IntoIter {
+                phantom: PhantomData,
+                ptr: begin,
+                end,
+            }
+        }
+    }
+}
+
+impl<T> Drop for IntoIter<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            let remaining = self.end.offset_from(self.ptr) as usize;
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr as *mut T, remaining));
+        }
+    }
+}
+
+// How many times a `Token` with each id has been dropped.
+const MAX_ID: usize = 16;
+static DROPS: [AtomicUsize; MAX_ID] = [const { AtomicUsize::new(0) }; MAX_ID];
+
+pub fn drops_of(id: usize) -> usize {
+    DROPS[id].load(Ordering::SeqCst)
+}
+
+// An element that counts its drops. In place of a `Box<u32>`, where a second drop
+// would be a double free, it makes every extra drop visible without aborting.
+pub struct Token {
+    id: usize,
+}
+
+impl Token {
+    pub fn new(id: usize) -> Self {
+        assert!(id < MAX_ID);
+        Token { id }
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        DROPS[self.id].fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+impl<'bump, T: 'bump> Iterator for IntoIter<'bump, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        unsafe {
+            if self.ptr == self.end {
+                None
+            } else {
+                let old = self.ptr;
+                self.ptr = self.ptr.add(1);
+                Some(ptr::read(old))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact = unsafe { self.end.offset_from(self.ptr) as usize };
+        (exact, Some(exact))
+    }
+
+    // Skips `n` elements in O(1). `ptr` is moved past the skipped elements before
+    // they are dropped in place, so the iterator no longer owns them, and the target
+    // is then read by `next`. If fewer than `n + 1` elements remain, all of them are
+    // dropped and `None` is returned.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let skip = n.min(self.size_hint().0);
+        unsafe {
+            let skipped = self.ptr as *mut T;
+            self.ptr = self.ptr.add(skip);
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(skipped, skip));
+        }
+        self.next()
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup a vector of tokens 1..=6 and turn it into an owning iterator.
+    let bump = Bump::new();
+    let mut vec = Vec::new_in(&bump);
+    for id in 1..=6 {
+        vec.push(Token::new(id));
+    }
+    let mut iter = vec.into_iter();
+
+    // 2. Skip three tokens and take the fourth.
+    let fourth = iter.nth(3).expect("six tokens");
+    println!("nth(3) returned token {}, {} left", fourth.id, iter.size_hint().0);
+
+    // 3. Drop the returned token, then the iterator with the tokens it still owns.
+    drop(fourth);
+    drop(iter);
+
+    // 4. Verify each token was dropped exactly once.
+    let drops: std::vec::Vec<usize> = (1..=6).map(drops_of).collect();
+    println!("Drops of tokens 1..=6: {:?}", drops);
+    assert_eq!(drops, [1, 1, 1, 1, 1, 1], "DOUBLE DROP CONFIRMED: skipped tokens were dropped again!");
+    println!("Verification successful: every token was dropped exactly once.");
+}
@@ -0,0 +1,298 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::RefCell;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// A global allocator that forwards to `System` and, while tracking is on, remembers
+// the address of every live block. Freeing an address that is not live is a double
+// free: it is counted and *not* passed on to `System`, so the PoC can report it
+// instead of glibc aborting the process.
+pub struct DoubleFreeDetector;
+
+const SLOTS: usize = 256;
+static LIVE: [AtomicUsize; SLOTS] = [const { AtomicUsize::new(0) }; SLOTS];
+static TRACKING: AtomicBool = AtomicBool::new(false);
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static FREES: AtomicUsize = AtomicUsize::new(0);
+static DOUBLE_FREES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for DoubleFreeDetector {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && TRACKING.load(Ordering::SeqCst) {
+            ALLOCS.fetch_add(1, Ordering::SeqCst);
+            let addr = ptr as usize;
+            let slot = LIVE.iter().find(|slot| {
+                slot.compare_exchange(0, addr, Ordering::SeqCst, Ordering::SeqCst).is_ok()
+            });
+            assert!(slot.is_some(), "too many live blocks to track");
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if TRACKING.load(Ordering::SeqCst) {
+            let addr = ptr as usize;
+            let slot = LIVE.iter().find(|slot| {
+                slot.compare_exchange(addr, 0, Ordering::SeqCst, Ordering::SeqCst).is_ok()
+            });
+            if slot.is_none() {
+                DOUBLE_FREES.fetch_add(1, Ordering::SeqCst);
+                return;
+            }
+            FREES.fetch_add(1, Ordering::SeqCst);
+        }
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: DoubleFreeDetector = DoubleFreeDetector;
+
+impl DoubleFreeDetector {
+    // Tracks only the blocks allocated from now on. Everything allocated while
+    // tracking must also be freed before `stop` for the counts to be meaningful.
+    pub fn start() {
+        TRACKING.store(true, Ordering::SeqCst);
+    }
+
+    pub fn stop() {
+        TRACKING.store(false, Ordering::SeqCst);
+    }
+
+    // (blocks allocated, blocks freed, double frees) while tracking was on.
+    pub fn counts() -> (usize, usize, usize) {
+        (
+            ALLOCS.load(Ordering::SeqCst),
+            FREES.load(Ordering::SeqCst),
+            DOUBLE_FREES.load(Ordering::SeqCst),
+        )
+    }
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, self.cap);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    // Shortens the vector to `len` elements, dropping the rest. The dropped elements'
+    // bytes stay in the buffer until they are overwritten.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            unsafe {
+                let tail = ptr::slice_from_raw_parts_mut(self.buf.ptr().add(len), self.len - len);
+                self.len = len;
+                ptr::drop_in_place(tail);
+            }
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+// Like the crate's `Vec`, dropping the vector drops its elements in place.
+impl<'bump, T: 'bump> Drop for Vec<'bump, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.buf.ptr(), self.len));
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    // Removes consecutive duplicates. Each duplicate is dropped in place as soon as it
+    // is found, and each survivor is moved into the first free slot. Every element is
+    // therefore either dropped once here or kept, and `len` is lowered to the number
+    // of survivors, so the vector never counts a slot whose value was moved out.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        let len = self.len;
+        if len <= 1 {
+            return;
+        }
+        let p = self.buf.ptr();
+        let mut write = 1;
+        unsafe {
+            for read in 1..len {
+                if *p.add(read) == *p.add(write - 1) {
+                    ptr::drop_in_place(p.add(read));
+                } else {
+                    ptr::copy(p.add(read), p.add(write), 1);
+                    write += 1;
+                }
+            }
+        }
+        self.len = write;
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup a tracked vector of names with adjacent duplicates.
+    println!("Deduplicating [alice, alice, bob, bob, carol]");
+    DoubleFreeDetector::start();
+    let bump = Bump::new();
+    let mut names = Vec::new_in(&bump);
+    for name in ["alice", "alice", "bob", "bob", "carol"] {
+        names.push(String::from(name));
+    }
+
+    // 2. Dedup, then drop the vector with its survivors.
+    names.dedup();
+    let survivors = names.len();
+    drop(names);
+    drop(bump);
+    DoubleFreeDetector::stop();
+
+    // 3. Verify every block was freed exactly once.
+    let (allocs, frees, double_frees) = DoubleFreeDetector::counts();
+    println!("Survivors: {}", survivors);
+    println!("Blocks allocated: {}, freed: {}, freed twice: {}", allocs, frees, double_frees);
+    println!("Blocks never freed: {}", allocs - frees);
+    assert_eq!(double_frees, 0, "DOUBLE FREE CONFIRMED: a surviving String was freed twice!");
+    assert_eq!(allocs, frees);
+    println!("Verification successful: every String was freed exactly once.");
+}
@@ -0,0 +1,163 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+// It stays single-threaded in the patched variant; `SyncBump` below is shared instead.
+#[allow(dead_code)]
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+
+    pub fn allocation_count(&self) -> usize {
+        self.allocations.borrow().len()
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+// `Bump` is no longer declared `Sync`. Cross-thread sharing goes through `SyncBump`,
+// which keeps the same bookkeeping behind a `Mutex` so pushes are serialized.
+pub struct SyncBump {
+    allocations: Mutex<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+// SAFETY: the raw pointers are only ever touched while holding the lock, and every
+// block they point to is owned by the arena until it is dropped.
+unsafe impl Send for SyncBump {}
+unsafe impl Sync for SyncBump {}
+
+impl SyncBump {
+    pub fn new() -> Self {
+        SyncBump {
+            allocations: Mutex::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+
+    pub fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.lock().unwrap().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+
+    pub fn allocation_count(&self) -> usize {
+        self.allocations.lock().unwrap().len()
+    }
+}
+
+impl Drop for SyncBump {
+    fn drop(&mut self) {
+        let allocations = self.allocations.get_mut().unwrap_or_else(|e| e.into_inner());
+        for (ptr, layout) in allocations.iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+const THREADS: usize = 8;
+const ALLOCS_PER_THREAD: usize = 200_000;
+
+fn main() {
+    // 1. Setup a single arena shared by all threads, and a separate (correctly
+    // synchronized) counter of how many allocations actually returned.
+    let bump = Arc::new(SyncBump::new());
+    let succeeded = Arc::new(AtomicUsize::new(0));
+
+    // 2. Allocate from every thread at once. Each `alloc` now pushes its entry
+    // while holding the `Mutex`.
+    let handles: std::vec::Vec<_> = (0..THREADS)
+        .map(|t| {
+            let bump = Arc::clone(&bump);
+            let succeeded = Arc::clone(&succeeded);
+            thread::spawn(move || {
+                for i in 0..ALLOCS_PER_THREAD {
+                    let slot = bump.alloc(t * ALLOCS_PER_THREAD + i);
+                    assert_eq!(*slot, t * ALLOCS_PER_THREAD + i);
+                    succeeded.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        })
+        .collect();
+
+    // With the lock in place no thread can observe a half-finished push.
+    let panicked = handles.into_iter().map(|h| h.join()).filter(|r| r.is_err()).count();
+
+    // 3. Verify the bookkeeping. Every `alloc` left exactly one entry behind, so
+    // the arena's `Drop` frees each block once.
+    let expected = succeeded.load(Ordering::SeqCst);
+    let recorded = bump.allocation_count();
+    println!("Threads panicked:     {}", panicked);
+    println!("Allocations returned: {}", expected);
+    println!("Allocations recorded: {}", recorded);
+
+    assert_eq!(panicked, 0);
+    assert_eq!(recorded, expected);
+    println!("Verification successful: every allocation was recorded exactly once.");
+}
@@ -0,0 +1,117 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+
+    pub fn allocation_count(&self) -> usize {
+        self.allocations.borrow().len()
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+// `Bump` keeps its bookkeeping in a `RefCell`, whose borrow flag is a plain,
+// non-atomic counter. Declaring it `Sync` lets several threads run
+// `allocations.borrow_mut().push(..)` at the same time: two threads can both see
+// the flag as free, then race on the `Vec`'s length and buffer. Entries are lost
+// (leaking their blocks) or duplicated (freeing the same block twice on drop).
+unsafe impl Send for Bump {}
+unsafe impl Sync for Bump {}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+const THREADS: usize = 8;
+const ALLOCS_PER_THREAD: usize = 200_000;
+
+fn main() {
+    // 1. Setup a single arena shared by all threads, and a separate (correctly
+    // synchronized) counter of how many allocations actually returned.
+    let bump = Arc::new(Bump::new());
+    let succeeded = Arc::new(AtomicUsize::new(0));
+
+    // 2. Trigger BUG: allocate from every thread at once. Each `alloc` pushes an
+    // entry through the racy `RefCell`.
+    let handles: std::vec::Vec<_> = (0..THREADS)
+        .map(|t| {
+            let bump = Arc::clone(&bump);
+            let succeeded = Arc::clone(&succeeded);
+            thread::spawn(move || {
+                for i in 0..ALLOCS_PER_THREAD {
+                    let slot = bump.alloc(t * ALLOCS_PER_THREAD + i);
+                    assert_eq!(*slot, t * ALLOCS_PER_THREAD + i);
+                    succeeded.fetch_add(1, Ordering::SeqCst);
+                }
+            })
+        })
+        .collect();
+
+    // A thread may also die on a spurious "already borrowed" panic when it
+    // observes another thread's half-finished borrow.
+    let panicked = handles.into_iter().map(|h| h.join()).filter(|r| r.is_err()).count();
+
+    // 3. Verify the bookkeeping. Every successful `alloc` should have left exactly
+    // one entry behind. A mismatch means entries were lost or duplicated, and the
+    // arena's `Drop` will leak or double-free the affected blocks.
+    let expected = succeeded.load(Ordering::SeqCst);
+    let recorded = bump.allocation_count();
+    println!("Threads panicked:     {}", panicked);
+    println!("Allocations returned: {}", expected);
+    println!("Allocations recorded: {}", recorded);
+
+    assert_eq!(panicked, 0, "DATA RACE CONFIRMED: a thread hit a torn RefCell borrow!");
+    assert_eq!(recorded, expected, "DATA RACE CONFIRMED: bookkeeping entries were lost or duplicated!");
+    println!("Dropping the arena.");
+}
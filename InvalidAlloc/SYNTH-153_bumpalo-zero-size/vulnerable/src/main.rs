@@ -0,0 +1,134 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::RefCell;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// A global allocator that forwards to `System` and counts requests that break the
+// `GlobalAlloc` contract: calling `alloc` with a zero-size layout is undefined
+// behavior. glibc happens to return a valid pointer for it, so without the count
+// the bug would go unnoticed outside Miri.
+pub struct ContractCheckingAlloc;
+
+static ZERO_SIZE_REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for ContractCheckingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if layout.size() == 0 {
+            ZERO_SIZE_REQUESTS.fetch_add(1, Ordering::SeqCst);
+        }
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: ContractCheckingAlloc = ContractCheckingAlloc;
+
+impl ContractCheckingAlloc {
+    // Zero-size `alloc` calls made so far.
+    pub fn zero_size_requests() -> usize {
+        ZERO_SIZE_REQUESTS.load(Ordering::SeqCst)
+    }
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    // Copies `src` into a new arena slice.
+    pub fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        let layout = Layout::for_value(src);
+        unsafe {
+            let dst = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::copy_nonoverlapping(src.as_ptr(), dst, src.len());
+            std::slice::from_raw_parts_mut(dst, src.len())
+        }
+    }
+
+    pub fn allocation_count(&self) -> usize {
+        self.allocations.borrow().len()
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+impl Bump {
+    // Forwards every layout to the global allocator, including zero-size ones. A
+    // zero-size element type or an empty slice reaches `std::alloc::alloc` with
+    // `size == 0`, which the allocator contract forbids.
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup an arena and record how many zero-size requests happened so far.
+    let bump = Bump::new();
+    let before = ContractCheckingAlloc::zero_size_requests();
+
+    // 2. Trigger BUG: copy a slice of a zero-size type, then an empty slice.
+    let units = bump.alloc_slice_copy(&[(); 3]);
+    let empty = bump.alloc_slice_copy::<u64>(&[]);
+    println!("Allocated {} units and {} u64s ({} blocks recorded).", units.len(), empty.len(), bump.allocation_count());
+
+    // 3. Verify that no zero-size layout reached the global allocator.
+    let zero_size = ContractCheckingAlloc::zero_size_requests() - before;
+    println!("Zero-size alloc calls: {}", zero_size);
+    assert_eq!(zero_size, 0, "INVALID ALLOCATION CONFIRMED: alloc was called with a zero-size layout!");
+}
@@ -0,0 +1,103 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk.
+// `used` is the bump cursor: the offset of the first byte no allocation owns yet.
+pub struct Bump {
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            chunk,
+            used: Cell::new(0),
+        }
+    }
+
+    // `alloc` writes `val` through a `*mut T`, which requires the pointer returned
+    // by `alloc_layout` to be aligned for `T`.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+}
+
+// A type whose alignment is larger than the alignment of a `u8`, like a cache-line
+// padded counter or a SIMD vector.
+#[repr(align(16))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Block {
+    words: [u32; 4],
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+impl Bump {
+    // Carves the allocation at the current cursor without rounding it up to
+    // `layout.align()`. After an odd-sized allocation the cursor sits at an odd
+    // offset, and the next over-aligned type is handed a misaligned address.
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = self.used.get();
+        let end = match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => end,
+            _ => handle_alloc_error(layout),
+        };
+        self.used.set(end);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup: a single byte moves the cursor to offset 1.
+    let bump = Bump::new();
+    let tag = bump.alloc(0xFFu8);
+    println!("u8 at    {:p}", tag as *mut u8);
+
+    // 2. Trigger BUG: request the region `alloc::<Block>` would write into.
+    let layout = Layout::new::<Block>();
+    let block = bump.alloc_layout(layout);
+    println!("Block at {:p} (align {})", block.as_ptr(), layout.align());
+
+    // 3. Verify the alignment. Writing a `Block` through a misaligned pointer is
+    // undefined behavior, so it must land on a multiple of 16.
+    let misalignment = block.as_ptr() as usize % layout.align();
+    println!("Misalignment: {} bytes", misalignment);
+    assert_eq!(misalignment, 0, "MISALIGNMENT CONFIRMED: the over-aligned allocation is not 16-aligned!");
+}
@@ -0,0 +1,110 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::num::NonZeroU8;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// A handle record, as a registry might keep in an arena. `id` is never zero, so
+// `Option<Slot>` and `Option<NonZeroU8>` can use zero to mean `None`.
+#[derive(Debug)]
+pub struct Slot {
+    pub id: NonZeroU8,
+    pub refs: u32,
+}
+
+impl Default for Slot {
+    fn default() -> Self {
+        Slot {
+            id: NonZeroU8::MIN,
+            refs: 0,
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+impl Bump {
+    // Allocates `T::default()` instead of all-zero bytes. The value is built by the
+    // type itself, so it always satisfies the type's validity invariant. Types for
+    // which zero is the right starting value get it from their `Default` impl.
+    pub fn alloc_default<T: Default>(&self) -> &mut T {
+        self.alloc_with(T::default)
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup an arena.
+    let bump = Bump::new();
+
+    // 2. Allocate a fresh `Slot` from its `Default` impl.
+    let slot: &mut Slot = bump.alloc_default();
+
+    // 3. Verify the value. A `NonZeroU8` must never hold zero.
+    let id = slot.id;
+    println!("slot.id = {}, refs = {}", id.get(), slot.refs);
+    println!("Some(slot.id).is_none() = {}", Some(id).is_none());
+    assert_ne!(id.get(), 0, "INVALID VALUE CONFIRMED: a NonZeroU8 holds zero!");
+    assert!(Some(id).is_some());
+    println!("Verification successful: the new Slot holds a valid id.");
+}
@@ -0,0 +1,117 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::num::NonZeroU8;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// A handle record, as a registry might keep in an arena. `id` is never zero, so
+// `Option<Slot>` and `Option<NonZeroU8>` can use zero to mean `None`.
+#[derive(Debug)]
+pub struct Slot {
+    pub id: NonZeroU8,
+    pub refs: u32,
+}
+
+impl Default for Slot {
+    fn default() -> Self {
+        Slot {
+            id: NonZeroU8::MIN,
+            refs: 0,
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+impl Bump {
+    // Allocates a `T` with every byte set to zero, the arena version of
+    // `mem::zeroed::<T>()`, for any `T`. All-zero is a valid value for integers and
+    // raw pointers, but not for types with a validity invariant that excludes zero,
+    // such as `NonZeroU8`, references or `Box`. For those, the returned `&mut T`
+    // points to an invalid value, and reading it is undefined behavior.
+    pub fn alloc_zeroed<T>(&self) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr();
+            ptr::write_bytes(p, 0, layout.size());
+            &mut *(p as *mut T)
+        }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup an arena.
+    let bump = Bump::new();
+
+    // 2. Trigger BUG: allocate a fresh `Slot` as all-zero bytes.
+    let slot: &mut Slot = bump.alloc_zeroed();
+
+    // 3. Verify the value. A `NonZeroU8` must never hold zero. Once it does, layout
+    // optimizations that rely on the niche give wrong answers: depending on the
+    // optimizer, `Some(id)` may even compare as `None`.
+    let id = slot.id;
+    println!("slot.id = {}, refs = {}", id.get(), slot.refs);
+    println!("Some(slot.id).is_none() = {}", Some(id).is_none());
+    assert_ne!(id.get(), 0, "INVALID VALUE CONFIRMED: a NonZeroU8 holds zero!");
+}
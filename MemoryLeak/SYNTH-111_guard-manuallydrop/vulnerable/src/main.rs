@@ -0,0 +1,93 @@
+// Minimal use, example:
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// SECTION 1: MINIMAL TYPES, TRAITS, AND HELPER FUNCTIONS
+/// A global allocator that forwards to `System` and tracks the number of live bytes,
+/// so the PoC can measure the leak instead of assuming it.
+pub struct CountingAlloc;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            LIVE_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            LIVE_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+impl CountingAlloc {
+    /// Bytes currently allocated and not yet freed.
+    pub fn live_bytes() -> usize {
+        LIVE_BYTES.load(Ordering::SeqCst)
+    }
+}
+
+/// A generic guard that owns a value through `ManuallyDrop`, so that the guard's own
+/// `Drop` impl decides when (and whether) the value is destroyed.
+pub struct Guard<T> {
+    inner: ManuallyDrop<T>,
+}
+
+impl<T> Guard<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: ManuallyDrop::new(value),
+        }
+    }
+
+    pub fn get(&self) -> &T {
+        &self.inner
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+impl<T> Drop for Guard<T> {
+    fn drop(&mut self) {
+        // The guard's cleanup runs here, but it never calls `ManuallyDrop::drop` on
+        // `inner`. Because the field is `ManuallyDrop`, the compiler does not drop
+        // it either, so every resource owned by `T` is leaked.
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+const GUARDS: usize = 50;
+const VALUE_SIZE: usize = 10 * 1024 * 1024;
+
+fn main() {
+    // 1. Record the baseline before any guard exists.
+    let baseline = CountingAlloc::live_bytes();
+
+    // 2. Trigger BUG: create and drop many guards around a 10MB buffer each.
+    for _ in 0..GUARDS {
+        let guard = Guard::new(vec![0u8; VALUE_SIZE]);
+        assert_eq!(guard.get().len(), VALUE_SIZE);
+        drop(guard);
+    }
+
+    // 3. Verify the leak: every guard is gone, so no bytes should remain.
+    let leaked = CountingAlloc::live_bytes() - baseline;
+    println!("Dropped {} guards of {} bytes each.", GUARDS, VALUE_SIZE);
+    println!("Bytes still allocated: {} ({} MB)", leaked, leaked / (1024 * 1024));
+    assert_eq!(leaked, 0, "MEMORY LEAK CONFIRMED: the guarded values were never dropped!");
+}
@@ -0,0 +1,136 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// Minimal use, example:
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::RefCell;
+use std::mem;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// SECTION 1: MINIMAL TYPES, TRAITS, AND HELPER FUNCTIONS
+/// A global allocator that forwards to `System` and tracks the number of live bytes,
+/// so the PoC can measure the leak instead of assuming it.
+pub struct CountingAlloc;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            LIVE_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            LIVE_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+impl CountingAlloc {
+    /// Bytes currently allocated and not yet freed.
+    pub fn live_bytes() -> usize {
+        LIVE_BYTES.load(Ordering::SeqCst)
+    }
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+/// Simplified `bumpalo::Bump`: every allocation is its own block, and all blocks are
+/// freed together when the arena is dropped.
+pub struct Bump {
+    allocations: RefCell<Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let block = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((block, layout));
+        unsafe {
+            let p = block.as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+
+    /// Consumes the arena without freeing any of its blocks. This is a deliberate leak
+    /// for an arena that lives until the process exits, where tearing it down is
+    /// wasted work.
+    pub fn leak_contents(self) {
+        mem::forget(self);
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+/// Serves one request out of its own scratch arena and returns a checksum of the
+/// scratch buffer. The arena is dropped when the function returns, so its blocks go
+/// back to the allocator and the memory used per request does not accumulate.
+fn handle_request(id: usize) -> usize {
+    let scratch = Bump::new();
+    let buf = scratch.alloc([0u8; SCRATCH_SIZE]);
+    buf[id % SCRATCH_SIZE] = 1;
+    buf.iter().map(|&b| b as usize).sum()
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+const REQUESTS: usize = 1000;
+const SCRATCH_SIZE: usize = 4096;
+
+fn main() {
+    // 1. Setup a process-wide arena holding data that is needed until exit.
+    let baseline = CountingAlloc::live_bytes();
+    let config = Bump::new();
+    config.alloc(*b"log_level=debug;workers=8;");
+    let intended = CountingAlloc::live_bytes() - baseline;
+
+    // 2. Serve many requests, each in its own scratch arena.
+    for id in 0..REQUESTS {
+        assert_eq!(handle_request(id), 1);
+    }
+
+    // 3. Shut down. The process-wide arena is leaked on purpose, exactly once.
+    config.leak_contents();
+
+    // 4. Verify the leak. Everything still allocated beyond the intended leak of the
+    // process-wide arena was lost by the requests.
+    let accidental = CountingAlloc::live_bytes() - baseline - intended;
+    println!("Intended leak (process-wide arena): {} bytes", intended);
+    println!("Accidental leak ({} requests):    {} bytes", REQUESTS, accidental);
+    assert_eq!(accidental, 0, "MEMORY LEAK CONFIRMED: every request leaked its scratch arena!");
+    println!("Verification successful: only the intended, bounded leak remains.");
+}
@@ -0,0 +1,132 @@
+// Minimal use, example:
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// SECTION 1: MINIMAL TYPES, TRAITS, AND HELPER FUNCTIONS
+/// A global allocator that forwards to `System` and tracks the number of live bytes,
+/// so the PoC can measure the leak instead of assuming it.
+pub struct CountingAlloc;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            LIVE_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            LIVE_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+impl CountingAlloc {
+    /// Bytes currently allocated and not yet freed.
+    pub fn live_bytes() -> usize {
+        LIVE_BYTES.load(Ordering::SeqCst)
+    }
+}
+
+/// A minimal `tracing::Span` whose record lives behind an `Rc`, so every clone of
+/// a span refers to the same `Inner`. The record is freed when the last clone is
+/// dropped.
+#[derive(Clone)]
+pub struct Span {
+    inner: Rc<Inner>,
+}
+
+impl Span {
+    /// Opens a root span whose recorded field values take `field_bytes` bytes.
+    pub fn new(name: &'static str, field_bytes: usize) -> Self {
+        Span {
+            inner: Rc::new(Inner {
+                name,
+                fields: vec![0u8; field_bytes],
+                parent: RefCell::new(None),
+                children: RefCell::new(Vec::new()),
+            }),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.inner.name
+    }
+
+    /// Bytes taken by the span's recorded field values.
+    pub fn field_bytes(&self) -> usize {
+        self.inner.fields.len()
+    }
+
+    /// Opens a child span. The parent keeps a handle to each of its children.
+    pub fn child(&self, name: &'static str, field_bytes: usize) -> Span {
+        let child = Span::new(name, field_bytes);
+        child.set_parent(self);
+        self.inner.children.borrow_mut().push(child.clone());
+        child
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+/// The shared span record.
+pub struct Inner {
+    name: &'static str,
+    fields: Vec<u8>,
+    // The parent is held through a strong `Span`. Together with the parent's strong
+    // handle to the child in `children`, every parent/child pair forms a reference
+    // cycle: neither count ever drops to zero, so neither record is ever freed.
+    parent: RefCell<Option<Span>>,
+    children: RefCell<Vec<Span>>,
+}
+
+impl Span {
+    fn set_parent(&self, parent: &Span) {
+        *self.inner.parent.borrow_mut() = Some(parent.clone());
+    }
+
+    pub fn parent(&self) -> Option<Span> {
+        self.inner.parent.borrow().clone()
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+const CYCLES: usize = 1000;
+const FIELD_BYTES: usize = 1024;
+
+fn main() {
+    // 1. Record the baseline before any span exists.
+    let baseline = CountingAlloc::live_bytes();
+
+    // 2. Trigger BUG: open a request span with a child span, as one traced request
+    // would, and drop both handles. Repeat to amplify the leak.
+    for _ in 0..CYCLES {
+        let request = Span::new("request", FIELD_BYTES);
+        let query = request.child("db_query", FIELD_BYTES);
+        assert_eq!(query.parent().map(|p| p.name()), Some("request"));
+        assert_eq!(query.field_bytes(), FIELD_BYTES);
+        drop(query);
+        drop(request);
+    }
+
+    // 3. Verify the leak: no span handle is left, so no span record should remain.
+    let leaked = CountingAlloc::live_bytes() - baseline;
+    println!("Dropped {} parent/child span pairs.", CYCLES);
+    println!("Bytes still allocated: {} ({} per pair)", leaked, leaked / CYCLES);
+    assert_eq!(leaked, 0, "MEMORY LEAK CONFIRMED: the span records were never freed!");
+}
@@ -2,10 +2,15 @@
 // SECTION 1: MINIMAL DEPENDENCIES
 
 use std::alloc::Layout;
+use std::borrow::Borrow;
+use std::cell::Cell;
 use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::io;
 use std::marker::PhantomData;
-use std::mem;
+use std::mem::{self, MaybeUninit};
 use std::ptr::{self, NonNull};
+use std::sync::Mutex;
 
 // Helper functions
 fn capacity_overflow() -> ! {
@@ -20,19 +25,100 @@ unsafe fn arith_offset<T>(p: *const T, offset: isize) -> *const T {
     p.offset(offset)
 }
 
-unsafe fn offset_from<T>(p: *const T, origin: *const T) -> isize
-where
-    T: Sized,
-{
-    let pointee_size = mem::size_of::<T>();
-    assert!(0 < pointee_size && pointee_size <= isize::MAX as usize);
-    isize::wrapping_sub(p as _, origin as _) / (pointee_size as isize)
+// Opt-in guard-page backend (`--features guard-pages`, Linux only). Each allocation
+// gets its own anonymous mapping, placed so that the block ends right before a
+// `PROT_NONE` page. Writing past the end of an arena allocation then faults at once
+// instead of silently corrupting a neighbouring block.
+#[cfg(all(target_os = "linux", feature = "guard-pages"))]
+mod guard_pages {
+    use std::alloc::Layout;
+    use std::os::raw::{c_int, c_long, c_void};
+
+    const PROT_NONE: c_int = 0x0;
+    const PROT_READ: c_int = 0x1;
+    const PROT_WRITE: c_int = 0x2;
+    const MAP_PRIVATE: c_int = 0x02;
+    const MAP_ANONYMOUS: c_int = 0x20;
+    const MAP_FAILED: *mut c_void = !0 as *mut c_void;
+    const _SC_PAGESIZE: c_int = 30;
+
+    extern "C" {
+        fn mmap(addr: *mut c_void, len: usize, prot: c_int, flags: c_int, fd: c_int, off: i64) -> *mut c_void;
+        fn munmap(addr: *mut c_void, len: usize) -> c_int;
+        fn mprotect(addr: *mut c_void, len: usize, prot: c_int) -> c_int;
+        fn sysconf(name: c_int) -> c_long;
+    }
+
+    fn page_size() -> usize {
+        unsafe { sysconf(_SC_PAGESIZE) as usize }
+    }
+
+    // Bytes of readable/writable pages needed in front of the guard page.
+    fn data_len(layout: Layout, page: usize) -> usize {
+        (layout.size().max(1) + page - 1) & !(page - 1)
+    }
+
+    pub unsafe fn alloc(layout: Layout) -> *mut u8 {
+        let page = page_size();
+        assert!(layout.align() <= page, "guard pages cannot satisfy alignment {}", layout.align());
+        let data = data_len(layout, page);
+        let base = mmap(std::ptr::null_mut(), data + page, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+        if base == MAP_FAILED {
+            return std::ptr::null_mut();
+        }
+        let base = base as *mut u8;
+        if mprotect(base.add(data) as *mut c_void, page, PROT_NONE) != 0 {
+            munmap(base as *mut c_void, data + page);
+            return std::ptr::null_mut();
+        }
+        // Push the block as far towards the guard page as its alignment allows.
+        let offset = (data - layout.size()) & !(layout.align() - 1);
+        base.add(offset)
+    }
+
+    pub unsafe fn dealloc(ptr: *mut u8, layout: Layout) {
+        let page = page_size();
+        // The block always starts within the first page of its mapping.
+        let base = (ptr as usize & !(page - 1)) as *mut c_void;
+        munmap(base, data_len(layout, page) + page);
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "guard-pages"))]
+use guard_pages::{alloc as sys_alloc, dealloc as sys_dealloc};
+#[cfg(not(all(target_os = "linux", feature = "guard-pages")))]
+use std::alloc::{alloc as sys_alloc, dealloc as sys_dealloc};
+
+// The system allocator must not be called with a size of 0. A zero-size block needs
+// no memory, so it gets an aligned dangling pointer instead. The arenas do not record
+// it, so it is never passed to `sys_dealloc`.
+fn zero_size_block(layout: Layout) -> Option<NonNull<u8>> {
+    if layout.size() == 0 {
+        Some(unsafe { NonNull::new_unchecked(layout.align() as *mut u8) })
+    } else {
+        None
+    }
+}
+
+// An arena block being handed out or released, as reported to the hook installed
+// with `Bump::set_hook` (`--features metrics`).
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocEvent {
+    Alloc { size: usize, align: usize },
+    Free { size: usize, align: usize },
 }
 
 // Simplified Bump allocator that correctly models arena-like deallocation on drop.
 pub struct Bump {
     // Use the standard library's Vec for internal bookkeeping.
     allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+    // Bytes in the blocks the arena owns right now, and the most it has owned at
+    // once, for profiling.
+    allocated: Cell<usize>,
+    high_water: Cell<usize>,
+    #[cfg(feature = "metrics")]
+    hook: Cell<Option<fn(AllocEvent)>>,
 }
 
 impl Bump {
@@ -40,6 +126,25 @@ impl Bump {
         Bump {
             // Call the standard Vec's `new` method.
             allocations: RefCell::new(std::vec::Vec::new()),
+            allocated: Cell::new(0),
+            high_water: Cell::new(0),
+            #[cfg(feature = "metrics")]
+            hook: Cell::new(None),
+        }
+    }
+
+    // Reports every block this arena allocates from now on, and every block it frees
+    // on reset or drop, to `hook`. Replaces any previously installed hook. Zero-size layouts
+    // take no block and are not reported.
+    #[cfg(feature = "metrics")]
+    pub fn set_hook(&self, hook: fn(AllocEvent)) {
+        self.hook.set(Some(hook));
+    }
+
+    #[cfg(feature = "metrics")]
+    fn emit(&self, event: AllocEvent) {
+        if let Some(hook) = self.hook.get() {
+            hook(event);
         }
     }
 
@@ -49,6 +154,7 @@ impl Bump {
     }
 
     #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
     pub fn alloc_with<F, T>(&self, f: F) -> &mut T
     where
         F: FnOnce() -> T,
@@ -61,23 +167,232 @@ impl Bump {
         }
     }
 
+    // Allocates space for a `T` without initializing it. The caller must `write` the
+    // value before calling `assume_init_ref`/`assume_init_mut`; reading it before
+    // that is undefined behavior.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_uninit<T>(&self) -> &mut MaybeUninit<T> {
+        let layout = Layout::new::<T>();
+        unsafe { &mut *(self.alloc_layout(layout).as_ptr() as *mut MaybeUninit<T>) }
+    }
+
+    // Collects `iter` and moves its items into a single arena slice. The iterator's
+    // length is not trusted up front: the items are gathered in a heap `Vec` first, so
+    // the slice is sized by what was actually yielded.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_iter<T, I>(&self, iter: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut items: std::vec::Vec<T> = iter.into_iter().collect();
+        let len = items.len();
+        if len == 0 {
+            return &mut [];
+        }
+        let layout = Layout::array::<T>(len).unwrap_or_else(|_| capacity_overflow());
+        unsafe {
+            let dst = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::copy_nonoverlapping(items.as_ptr(), dst, len);
+            // The items now live in the arena; only free the heap buffer.
+            items.set_len(0);
+            std::slice::from_raw_parts_mut(dst, len)
+        }
+    }
+
+    // Clones every element of `src` into a new arena slice. If a `clone` panics, the
+    // clones already written are dropped before unwinding continues, so nothing
+    // leaks and no uninitialized slot is dropped. The block itself stays in the
+    // arena until it is dropped.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_clone<T: Clone>(&self, src: &[T]) -> &mut [T] {
+        struct DropPrefix<T> {
+            dst: *mut T,
+            initialized: usize,
+        }
+
+        impl<T> Drop for DropPrefix<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.dst, self.initialized));
+                }
+            }
+        }
+
+        let layout = Layout::for_value(src);
+        let dst = self.alloc_layout(layout).as_ptr() as *mut T;
+        let mut guard = DropPrefix { dst, initialized: 0 };
+        for x in src {
+            unsafe { ptr::write(dst.add(guard.initialized), x.clone()) };
+            guard.initialized += 1;
+        }
+        mem::forget(guard);
+        unsafe { std::slice::from_raw_parts_mut(dst, src.len()) }
+    }
+
     // This function now panics on allocation failure, removing the need for the unstable `AllocError`.
     fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
-        let ptr = unsafe { std::alloc::alloc(layout) };
+        if let Some(block) = zero_size_block(layout) {
+            return block;
+        }
+        let ptr = unsafe { sys_alloc(layout) };
         let non_null_ptr = match NonNull::new(ptr) {
             Some(p) => p,
             None => handle_alloc_error(layout),
         };
+        // Every caller writes a `T` through this pointer, so a backend that hands out
+        // misaligned memory (a bump cursor that skips the rounding, see SYNTH-162)
+        // must fail here rather than at the first typed access.
+        debug_assert!(
+            non_null_ptr.as_ptr() as usize & (layout.align() - 1) == 0,
+            "allocation at {:p} is not aligned to {}",
+            non_null_ptr.as_ptr(),
+            layout.align()
+        );
         self.allocations.borrow_mut().push((non_null_ptr, layout));
+        let allocated = self.allocated.get() + layout.size();
+        self.allocated.set(allocated);
+        self.high_water.set(self.high_water.get().max(allocated));
+        #[cfg(feature = "metrics")]
+        self.emit(AllocEvent::Alloc { size: layout.size(), align: layout.align() });
         non_null_ptr
     }
+
+    // Bytes in the blocks the arena currently owns.
+    pub fn allocated_bytes(&self) -> usize {
+        self.allocated.get()
+    }
+
+    // The most bytes the arena has owned at once over its lifetime. `reset` does not
+    // lower it; only `reset_high_water` does.
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water.get()
+    }
+
+    // Restarts peak tracking from the bytes the arena owns right now.
+    pub fn reset_high_water(&self) {
+        self.high_water.set(self.allocated.get());
+    }
+
+    // Frees every block and leaves the arena empty, ready to be reused. Taking
+    // `&mut self` requires that nothing allocated from the arena is still borrowed.
+    pub fn reset(&mut self) {
+        #[cfg(feature = "metrics")]
+        let hook = self.hook.get();
+        for (ptr, layout) in self.allocations.get_mut().drain(..) {
+            unsafe {
+                sys_dealloc(ptr.as_ptr(), layout);
+            }
+            #[cfg(feature = "metrics")]
+            if let Some(hook) = hook {
+                hook(AllocEvent::Free { size: layout.size(), align: layout.align() });
+            }
+        }
+        self.allocated.set(0);
+    }
+
+    // `(size, align)` of every block the arena currently owns, in allocation order.
+    pub fn allocation_layouts(&self) -> std::vec::Vec<(usize, usize)> {
+        self.allocations
+            .borrow()
+            .iter()
+            .map(|(_, layout)| (layout.size(), layout.align()))
+            .collect()
+    }
+
+    // Snapshot of the arena's bookkeeping, read under a single borrow.
+    pub fn stats(&self) -> BumpStats {
+        let allocations = self.allocations.borrow();
+        BumpStats {
+            allocations: allocations.len(),
+            bytes: allocations.iter().map(|(_, layout)| layout.size()).sum(),
+            largest_layout: allocations.iter().map(|(_, layout)| *layout).max_by_key(|layout| layout.size()),
+        }
+    }
+
+    // Consumes the arena without freeing any of its blocks. This is a deliberate leak
+    // for an arena that lives until the process exits, where tearing it down is
+    // wasted work. Calling it on short-lived arenas leaks memory without bound.
+    pub fn leak_contents(self) {
+        mem::forget(self);
+    }
+}
+
+// Point-in-time view of a `Bump`, as returned by `Bump::stats`.
+#[derive(Clone, Debug)]
+pub struct BumpStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    pub largest_layout: Option<Layout>,
 }
 
 impl Drop for Bump {
     fn drop(&mut self) {
-        for (ptr, layout) in self.allocations.get_mut().iter() {
+        self.reset();
+    }
+}
+
+// Thread-safe counterpart of `Bump`: the allocation list sits behind a `Mutex`
+// instead of a `RefCell`, so it can be shared between threads without racing on
+// the bookkeeping. Blocks come from the same backend as `Bump`'s, including the
+// guard-page one.
+pub struct SyncBump {
+    allocations: Mutex<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+// SAFETY: the raw pointers are only ever touched while holding the lock, and every
+// block they point to is owned by the arena until it is dropped.
+unsafe impl Send for SyncBump {}
+unsafe impl Sync for SyncBump {}
+
+impl SyncBump {
+    pub fn new() -> Self {
+        SyncBump {
+            allocations: Mutex::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+
+    pub fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        if let Some(block) = zero_size_block(layout) {
+            return block;
+        }
+        let ptr = unsafe { sys_alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        debug_assert!(
+            non_null_ptr.as_ptr() as usize & (layout.align() - 1) == 0,
+            "allocation at {:p} is not aligned to {}",
+            non_null_ptr.as_ptr(),
+            layout.align()
+        );
+        self.allocations.lock().unwrap().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+
+    pub fn allocation_count(&self) -> usize {
+        self.allocations.lock().unwrap().len()
+    }
+}
+
+impl Drop for SyncBump {
+    fn drop(&mut self) {
+        let allocations = self.allocations.get_mut().unwrap_or_else(|e| e.into_inner());
+        for (ptr, layout) in allocations.iter() {
             unsafe {
-                std::alloc::dealloc(ptr.as_ptr(), *layout);
+                sys_dealloc(ptr.as_ptr(), *layout);
             }
         }
     }
@@ -126,6 +441,59 @@ impl<'a, T> RawVec<'a, T> {
     fn cap(&self) -> usize { self.cap }
 }
 
+// Counts the elements a bulk write has initialized and stores the count into the
+// vector's `len` when dropped. If a `clone` panics part way through, unwinding
+// drops the guard, so `len` covers exactly the slots written so far: no
+// uninitialized slot is ever exposed to the vector's readers or to `truncate`.
+struct SetLenOnDrop<'a> {
+    len: &'a mut usize,
+    local_len: usize,
+}
+
+impl<'a> SetLenOnDrop<'a> {
+    #[inline]
+    fn new(len: &'a mut usize) -> Self {
+        SetLenOnDrop {
+            local_len: *len,
+            len,
+        }
+    }
+
+    #[inline]
+    fn increment_len(&mut self, increment: usize) {
+        self.local_len += increment;
+    }
+}
+
+impl Drop for SetLenOnDrop<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        *self.len = self.local_len;
+    }
+}
+
+// Tracks an in-place compaction: `0..write` holds the survivors, `write..read` is a
+// gap of moved-out or dropped slots, and `read..len` has not been examined yet. If
+// the key function or a destructor panics, dropping the guard shifts the unexamined
+// tail down over the gap and shortens the vector to match, so every element the
+// vector still counts is live and none is dropped twice.
+struct FillGapOnDrop<'a, 'bump, T> {
+    read: usize,
+    write: usize,
+    vec: &'a mut Vec<'bump, T>,
+}
+
+impl<T> Drop for FillGapOnDrop<'_, '_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            let p = self.vec.buf.ptr();
+            let tail = self.vec.len - self.read;
+            ptr::copy(p.add(self.read), p.add(self.write), tail);
+            self.vec.len = self.write + tail;
+        }
+    }
+}
+
 pub struct Vec<'bump, T: 'bump> {
     buf: RawVec<'bump, T>,
     len: usize,
@@ -139,13 +507,159 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    // Creates an empty vector with room for `capacity` elements, so the first
+    // `capacity` pushes do not grow the buffer. A capacity of 0 does not allocate.
+    pub fn with_capacity_in(bump: &'bump Bump, capacity: usize) -> Vec<'bump, T> {
+        let mut vec = Vec::new_in(bump);
+        vec.reserve(capacity);
+        vec
+    }
+
+    // Moves the elements of a heap `Vec` into a new arena vector, in order. Each
+    // element is moved exactly once; the heap buffer is freed when the consumed
+    // `std::vec::IntoIter` is dropped at the end of the call.
+    pub fn from_std_vec_in(bump: &'bump Bump, v: std::vec::Vec<T>) -> Vec<'bump, T> {
+        let mut vec = Vec::with_capacity_in(bump, v.len());
+        vec.extend(v);
+        vec
+    }
+
+    // Concatenates `slices` into a new arena vector. The total length is summed with
+    // `checked_add` first, so the buffer is allocated once and each slice is copied
+    // into it in bulk.
+    pub fn from_slices_in(bump: &'bump Bump, slices: &[&[T]]) -> Vec<'bump, T>
+    where
+        T: Copy,
+    {
+        let total = slices
+            .iter()
+            .try_fold(0usize, |total, s| total.checked_add(s.len()))
+            .unwrap_or_else(|| capacity_overflow());
+        let mut vec: Vec<T> = Vec::with_capacity_in(bump, total);
+        for s in slices {
+            unsafe {
+                ptr::copy_nonoverlapping(s.as_ptr(), vec.buf.ptr().add(vec.len), s.len());
+            }
+            vec.len += s.len();
+        }
+        vec
+    }
+
     #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+    #[inline] pub fn as_ptr(&self) -> *const T { self.buf.ptr() as *const T }
     #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
 
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.buf.ptr(), self.len) }
+    }
+
+    // Returns the element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    // Mutable counterpart of `get`. The reference borrows the vector mutably, so it
+    // cannot outlive a reallocation.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    // Mutable counterpart of `iter`. The iterator borrows the vector mutably, so it
+    // cannot be pushed to (and reallocated) while the iterator is alive.
+    #[inline]
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    // Searches a sorted vector for `x`. `Err` holds the index where `x` could be
+    // inserted to keep the vector sorted.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.as_slice().binary_search(x)
+    }
+
+    // Rotates the elements in place so that the element at `mid` becomes the first.
+    // Panics if `mid > len()`. The bound is checked here rather than in
+    // `<[T]>::rotate_left`, so that the panic points at the caller.
+    #[track_caller]
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len, "rotation point {} exceeds length {}", mid, self.len);
+        self.as_mut_slice().rotate_left(mid);
+    }
+
+    // Rotates the elements in place so that the last `k` elements come first.
+    // Panics if `k > len()`.
+    #[track_caller]
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len, "rotation count {} exceeds length {}", k, self.len);
+        self.as_mut_slice().rotate_right(k);
+    }
+
+    // Divides the elements into `[0, mid)` and `[mid, len)`. Panics if `mid > len()`.
+    #[inline]
+    #[track_caller]
+    pub fn split_at(&self, mid: usize) -> (&[T], &[T]) {
+        self.as_slice().split_at(mid)
+    }
+
+    // Mutable counterpart of `split_at`. The two halves do not overlap, so both can be
+    // written at once; together they borrow the vector mutably.
+    #[inline]
+    #[track_caller]
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        self.as_mut_slice().split_at_mut(mid)
+    }
+
     pub fn reserve(&mut self, additional: usize) {
         self.buf.reserve(self.len, additional);
     }
 
+    // Forces the length to `new_len`.
+    //
+    // The caller must ensure `new_len <= capacity()` and that every element in
+    // `old_len..new_len` has been initialized, e.g. through `spare_capacity_mut`.
+    #[inline]
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        self.len = new_len;
+    }
+
+    // The uninitialized tail of the buffer, between `len` and the capacity. Writes
+    // here are committed with `set_len`.
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.buf.ptr().add(self.len) as *mut MaybeUninit<T>,
+                self.buf.cap() - self.len,
+            )
+        }
+    }
+
     #[inline]
     pub fn push(&mut self, value: T) {
         if self.len == self.buf.cap() {
@@ -157,6 +671,142 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
             self.len += 1;
         }
     }
+
+    // Consumes the vector without dropping its elements and returns them as a slice
+    // that lives as long as the arena. Unlike `std::vec::Vec::leak`, the slice is
+    // bounded by `'bump`, not `'static`, so the arena cannot be dropped while it is
+    // still in use.
+    pub fn leak(self) -> &'bump mut [T] {
+        let mut this = mem::ManuallyDrop::new(self);
+        unsafe { std::slice::from_raw_parts_mut(this.as_mut_ptr(), this.len) }
+    }
+
+    // The crate's name for `leak`: freezes the vector into a slice of exactly `len`
+    // elements. The spare capacity stays allocated in the arena but is not part of
+    // the slice. The elements are never dropped; the arena only frees their bytes.
+    pub fn into_bump_slice(self) -> &'bump mut [T] {
+        self.leak()
+    }
+
+    // Shortens the vector to `len` elements, dropping the rest. `len` is lowered
+    // before the tail is dropped, so a panicking destructor cannot cause a second
+    // drop of the same elements.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            unsafe {
+                let tail = std::slice::from_raw_parts_mut(self.buf.ptr().add(len), self.len - len);
+                self.len = len;
+                ptr::drop_in_place(tail);
+            }
+        }
+    }
+
+    // Removes all but the first of each run of consecutive elements with equal keys,
+    // dropping the removed elements. Survivors keep their order, as with
+    // `std::vec::Vec::dedup_by_key`.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        let len = self.len;
+        if len <= 1 {
+            return;
+        }
+        let p = self.buf.ptr();
+        let mut gap = FillGapOnDrop {
+            read: 1,
+            write: 1,
+            vec: self,
+        };
+        unsafe {
+            while gap.read < len {
+                let cur = p.add(gap.read);
+                let prev = p.add(gap.write - 1);
+                if key(&mut *cur) == key(&mut *prev) {
+                    // Step past the duplicate before dropping it, so a panicking
+                    // destructor leaves it in the gap rather than in the tail.
+                    gap.read += 1;
+                    ptr::drop_in_place(cur);
+                } else {
+                    ptr::copy(cur, p.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+            gap.vec.len = gap.write;
+            mem::forget(gap);
+        }
+    }
+
+    // Grows with clones of `value` or truncates to `new_len`. When growing, the last
+    // slot takes `value` itself, so only `new_len - len - 1` clones are made.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let len = self.len;
+        if new_len > len {
+            let extra = new_len - len;
+            self.reserve(extra);
+            unsafe {
+                let mut dst = self.buf.ptr().add(len);
+                let mut local_len = SetLenOnDrop::new(&mut self.len);
+                for _ in 1..extra {
+                    ptr::write(dst, value.clone());
+                    dst = dst.add(1);
+                    local_len.increment_len(1);
+                }
+                ptr::write(dst, value);
+                local_len.increment_len(1);
+            }
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    // Overwrites each of the `len` elements with a clone of `value`, dropping the old
+    // ones. The last element takes `value` itself. Spare capacity is not touched.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        if let Some((last, rest)) = self.as_mut_slice().split_last_mut() {
+            for slot in rest {
+                *slot = value.clone();
+            }
+            *last = value;
+        }
+    }
+
+    // Like `fill`, but each element is replaced with the next value returned by `f`.
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        for slot in self.as_mut_slice() {
+            *slot = f();
+        }
+    }
+
+    // Appends clones of every element of `other`, growing the buffer at most once.
+    // The clones are written straight into the spare capacity; `SetLenOnDrop` keeps
+    // `len` in step with them if one of the `clone` calls panics.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+        unsafe {
+            let mut dst = self.buf.ptr().add(self.len);
+            let mut local_len = SetLenOnDrop::new(&mut self.len);
+            for x in other {
+                ptr::write(dst, x.clone());
+                dst = dst.add(1);
+                local_len.increment_len(1);
+            }
+        }
+    }
 }
 
 impl<'bump, T: 'bump> Extend<T> for Vec<'bump, T> {
@@ -170,6 +820,76 @@ impl<'bump, T: 'bump> Extend<T> for Vec<'bump, T> {
     }
 }
 
+impl<'bump, T: 'bump + PartialEq> PartialEq for Vec<'bump, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<'bump, T: 'bump + Eq> Eq for Vec<'bump, T> {}
+
+// Lexicographic, like `std::vec::Vec`.
+impl<'bump, T: 'bump + PartialOrd> PartialOrd for Vec<'bump, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<'bump, T: 'bump + Ord> Ord for Vec<'bump, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<'bump, T: 'bump> AsRef<[T]> for Vec<'bump, T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'bump, T: 'bump> Borrow<[T]> for Vec<'bump, T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+// Lets `write!` format straight into arena memory, like `std::vec::Vec<u8>`.
+impl<'bump> io::Write for Vec<'bump, u8> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Borrowing iteration (`for x in &vec`). The returned iterator keeps `vec` borrowed,
+// so it cannot be reallocated while the iteration is in progress.
+impl<'a, 'bump, T: 'bump> IntoIterator for &'a Vec<'bump, T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> std::slice::Iter<'a, T> {
+        self.as_slice().iter()
+    }
+}
+
 // SECTION 2: PATCHED CODE
 
 // The IntoIter struct is now correctly parameterized with the lifetime `'bump`
@@ -222,17 +942,59 @@ impl<'bump, T: 'bump> Iterator for IntoIter<'bump, T> {
         }
     }
 
+    // Skips `n` elements in O(1) instead of calling `next` `n` times. The skipped
+    // elements are dropped in place as one slice, after `ptr` has been moved past
+    // them, so a panicking destructor cannot cause them to be dropped again. If
+    // fewer than `n + 1` elements remain, all of them are dropped and `None` is
+    // returned.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let skip = n.min(self.size_hint().0);
+        unsafe {
+            let skipped = if mem::size_of::<T>() == 0 {
+                self.ptr = arith_offset(self.ptr as *const i8, skip as isize) as *mut T;
+                NonNull::dangling().as_ptr()
+            } else {
+                let skipped = self.ptr as *mut T;
+                self.ptr = self.ptr.add(skip);
+                skipped
+            };
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(skipped, skip));
+        }
+        self.next()
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         let exact = if mem::size_of::<T>() == 0 {
             (self.end as usize).wrapping_sub(self.ptr as usize)
         } else {
-            unsafe { offset_from(self.end, self.ptr) as usize }
+            // `ptr` and `end` point into the same buffer, with `ptr <= end`, which is
+            // what `offset_from` requires. It is undefined behavior for pointers into
+            // different allocations.
+            unsafe { self.end.offset_from(self.ptr) as usize }
         };
         (exact, Some(exact))
     }
 }
 
+impl<'bump, T: 'bump> IntoIter<'bump, T> {
+    // Whether every element has been yielded. Also holds for zero-sized elements,
+    // where `ptr` and `end` count elements as byte offsets.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ptr == self.end
+    }
+
+    // Moves the elements not yielded yet into a heap `Vec`, in order. Each element is
+    // read out of the arena exactly once, so nothing is copied or dropped twice.
+    pub fn into_vec(self) -> std::vec::Vec<T> {
+        let mut out = std::vec::Vec::with_capacity(self.size_hint().0);
+        out.extend(self);
+        out
+    }
+}
+
 // SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
 
 fn main() {
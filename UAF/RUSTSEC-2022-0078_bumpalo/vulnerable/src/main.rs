@@ -2,10 +2,15 @@
 // SECTION 1: MINIMAL DEPENDENCIES
 
 use std::alloc::Layout;
+use std::borrow::Borrow;
+use std::cell::Cell;
 use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::io;
 use std::marker::PhantomData;
-use std::mem;
+use std::mem::{self, MaybeUninit};
 use std::ptr::{self, NonNull};
+use std::sync::Mutex;
 
 // Helper functions
 fn capacity_overflow() -> ! {
@@ -20,19 +25,100 @@ unsafe fn arith_offset<T>(p: *const T, offset: isize) -> *const T {
     p.offset(offset)
 }
 
-unsafe fn offset_from<T>(p: *const T, origin: *const T) -> isize
-where
-    T: Sized,
-{
-    let pointee_size = mem::size_of::<T>();
-    assert!(0 < pointee_size && pointee_size <= isize::MAX as usize);
-    isize::wrapping_sub(p as _, origin as _) / (pointee_size as isize)
+// Opt-in guard-page backend (`--features guard-pages`, Linux only). Each allocation
+// gets its own anonymous mapping, placed so that the block ends right before a
+// `PROT_NONE` page. Writing past the end of an arena allocation then faults at once
+// instead of silently corrupting a neighbouring block.
+#[cfg(all(target_os = "linux", feature = "guard-pages"))]
+mod guard_pages {
+    use std::alloc::Layout;
+    use std::os::raw::{c_int, c_long, c_void};
+
+    const PROT_NONE: c_int = 0x0;
+    const PROT_READ: c_int = 0x1;
+    const PROT_WRITE: c_int = 0x2;
+    const MAP_PRIVATE: c_int = 0x02;
+    const MAP_ANONYMOUS: c_int = 0x20;
+    const MAP_FAILED: *mut c_void = !0 as *mut c_void;
+    const _SC_PAGESIZE: c_int = 30;
+
+    extern "C" {
+        fn mmap(addr: *mut c_void, len: usize, prot: c_int, flags: c_int, fd: c_int, off: i64) -> *mut c_void;
+        fn munmap(addr: *mut c_void, len: usize) -> c_int;
+        fn mprotect(addr: *mut c_void, len: usize, prot: c_int) -> c_int;
+        fn sysconf(name: c_int) -> c_long;
+    }
+
+    fn page_size() -> usize {
+        unsafe { sysconf(_SC_PAGESIZE) as usize }
+    }
+
+    // Bytes of readable/writable pages needed in front of the guard page.
+    fn data_len(layout: Layout, page: usize) -> usize {
+        (layout.size().max(1) + page - 1) & !(page - 1)
+    }
+
+    pub unsafe fn alloc(layout: Layout) -> *mut u8 {
+        let page = page_size();
+        assert!(layout.align() <= page, "guard pages cannot satisfy alignment {}", layout.align());
+        let data = data_len(layout, page);
+        let base = mmap(std::ptr::null_mut(), data + page, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+        if base == MAP_FAILED {
+            return std::ptr::null_mut();
+        }
+        let base = base as *mut u8;
+        if mprotect(base.add(data) as *mut c_void, page, PROT_NONE) != 0 {
+            munmap(base as *mut c_void, data + page);
+            return std::ptr::null_mut();
+        }
+        // Push the block as far towards the guard page as its alignment allows.
+        let offset = (data - layout.size()) & !(layout.align() - 1);
+        base.add(offset)
+    }
+
+    pub unsafe fn dealloc(ptr: *mut u8, layout: Layout) {
+        let page = page_size();
+        // The block always starts within the first page of its mapping.
+        let base = (ptr as usize & !(page - 1)) as *mut c_void;
+        munmap(base, data_len(layout, page) + page);
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "guard-pages"))]
+use guard_pages::{alloc as sys_alloc, dealloc as sys_dealloc};
+#[cfg(not(all(target_os = "linux", feature = "guard-pages")))]
+use std::alloc::{alloc as sys_alloc, dealloc as sys_dealloc};
+
+// The system allocator must not be called with a size of 0. A zero-size block needs
+// no memory, so it gets an aligned dangling pointer instead. The arenas do not record
+// it, so it is never passed to `sys_dealloc`.
+fn zero_size_block(layout: Layout) -> Option<NonNull<u8>> {
+    if layout.size() == 0 {
+        Some(unsafe { NonNull::new_unchecked(layout.align() as *mut u8) })
+    } else {
+        None
+    }
+}
+
+// An arena block being handed out or released, as reported to the hook installed
+// with `Bump::set_hook` (`--features metrics`).
+#[cfg(feature = "metrics")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocEvent {
+    Alloc { size: usize, align: usize },
+    Free { size: usize, align: usize },
 }
 
 // Simplified Bump allocator that correctly models arena-like deallocation on drop.
 pub struct Bump {
     // Use the standard library's Vec for internal bookkeeping.
     allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+    // Bytes in the blocks the arena owns right now, and the most it has owned at
+    // once, for profiling.
+    allocated: Cell<usize>,
+    high_water: Cell<usize>,
+    #[cfg(feature = "metrics")]
+    hook: Cell<Option<fn(AllocEvent)>>,
 }
 
 impl Bump {
@@ -40,6 +126,25 @@ impl Bump {
         Bump {
             // Call the standard Vec's `new` method.
             allocations: RefCell::new(std::vec::Vec::new()),
+            allocated: Cell::new(0),
+            high_water: Cell::new(0),
+            #[cfg(feature = "metrics")]
+            hook: Cell::new(None),
+        }
+    }
+
+    // Reports every block this arena allocates from now on, and every block it frees
+    // on reset or drop, to `hook`. Replaces any previously installed hook. Zero-size layouts
+    // take no block and are not reported.
+    #[cfg(feature = "metrics")]
+    pub fn set_hook(&self, hook: fn(AllocEvent)) {
+        self.hook.set(Some(hook));
+    }
+
+    #[cfg(feature = "metrics")]
+    fn emit(&self, event: AllocEvent) {
+        if let Some(hook) = self.hook.get() {
+            hook(event);
         }
     }
 
@@ -49,6 +154,7 @@ impl Bump {
     }
 
     #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
     pub fn alloc_with<F, T>(&self, f: F) -> &mut T
     where
         F: FnOnce() -> T,
@@ -61,23 +167,232 @@ impl Bump {
         }
     }
 
+    // Allocates space for a `T` without initializing it. The caller must `write` the
+    // value before calling `assume_init_ref`/`assume_init_mut`; reading it before
+    // that is undefined behavior.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_uninit<T>(&self) -> &mut MaybeUninit<T> {
+        let layout = Layout::new::<T>();
+        unsafe { &mut *(self.alloc_layout(layout).as_ptr() as *mut MaybeUninit<T>) }
+    }
+
+    // Collects `iter` and moves its items into a single arena slice. The iterator's
+    // length is not trusted up front: the items are gathered in a heap `Vec` first, so
+    // the slice is sized by what was actually yielded.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_iter<T, I>(&self, iter: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut items: std::vec::Vec<T> = iter.into_iter().collect();
+        let len = items.len();
+        if len == 0 {
+            return &mut [];
+        }
+        let layout = Layout::array::<T>(len).unwrap_or_else(|_| capacity_overflow());
+        unsafe {
+            let dst = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::copy_nonoverlapping(items.as_ptr(), dst, len);
+            // The items now live in the arena; only free the heap buffer.
+            items.set_len(0);
+            std::slice::from_raw_parts_mut(dst, len)
+        }
+    }
+
+    // Clones every element of `src` into a new arena slice. If a `clone` panics, the
+    // clones already written are dropped before unwinding continues, so nothing
+    // leaks and no uninitialized slot is dropped. The block itself stays in the
+    // arena until it is dropped.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_clone<T: Clone>(&self, src: &[T]) -> &mut [T] {
+        struct DropPrefix<T> {
+            dst: *mut T,
+            initialized: usize,
+        }
+
+        impl<T> Drop for DropPrefix<T> {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.dst, self.initialized));
+                }
+            }
+        }
+
+        let layout = Layout::for_value(src);
+        let dst = self.alloc_layout(layout).as_ptr() as *mut T;
+        let mut guard = DropPrefix { dst, initialized: 0 };
+        for x in src {
+            unsafe { ptr::write(dst.add(guard.initialized), x.clone()) };
+            guard.initialized += 1;
+        }
+        mem::forget(guard);
+        unsafe { std::slice::from_raw_parts_mut(dst, src.len()) }
+    }
+
     // This function now panics on allocation failure, removing the need for the unstable `AllocError`.
     fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
-        let ptr = unsafe { std::alloc::alloc(layout) };
+        if let Some(block) = zero_size_block(layout) {
+            return block;
+        }
+        let ptr = unsafe { sys_alloc(layout) };
         let non_null_ptr = match NonNull::new(ptr) {
             Some(p) => p,
             None => handle_alloc_error(layout),
         };
+        // Every caller writes a `T` through this pointer, so a backend that hands out
+        // misaligned memory (a bump cursor that skips the rounding, see SYNTH-162)
+        // must fail here rather than at the first typed access.
+        debug_assert!(
+            non_null_ptr.as_ptr() as usize & (layout.align() - 1) == 0,
+            "allocation at {:p} is not aligned to {}",
+            non_null_ptr.as_ptr(),
+            layout.align()
+        );
         self.allocations.borrow_mut().push((non_null_ptr, layout));
+        let allocated = self.allocated.get() + layout.size();
+        self.allocated.set(allocated);
+        self.high_water.set(self.high_water.get().max(allocated));
+        #[cfg(feature = "metrics")]
+        self.emit(AllocEvent::Alloc { size: layout.size(), align: layout.align() });
         non_null_ptr
     }
+
+    // Bytes in the blocks the arena currently owns.
+    pub fn allocated_bytes(&self) -> usize {
+        self.allocated.get()
+    }
+
+    // The most bytes the arena has owned at once over its lifetime. `reset` does not
+    // lower it; only `reset_high_water` does.
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water.get()
+    }
+
+    // Restarts peak tracking from the bytes the arena owns right now.
+    pub fn reset_high_water(&self) {
+        self.high_water.set(self.allocated.get());
+    }
+
+    // Frees every block and leaves the arena empty, ready to be reused. Taking
+    // `&mut self` requires that nothing allocated from the arena is still borrowed.
+    pub fn reset(&mut self) {
+        #[cfg(feature = "metrics")]
+        let hook = self.hook.get();
+        for (ptr, layout) in self.allocations.get_mut().drain(..) {
+            unsafe {
+                sys_dealloc(ptr.as_ptr(), layout);
+            }
+            #[cfg(feature = "metrics")]
+            if let Some(hook) = hook {
+                hook(AllocEvent::Free { size: layout.size(), align: layout.align() });
+            }
+        }
+        self.allocated.set(0);
+    }
+
+    // `(size, align)` of every block the arena currently owns, in allocation order.
+    pub fn allocation_layouts(&self) -> std::vec::Vec<(usize, usize)> {
+        self.allocations
+            .borrow()
+            .iter()
+            .map(|(_, layout)| (layout.size(), layout.align()))
+            .collect()
+    }
+
+    // Snapshot of the arena's bookkeeping, read under a single borrow.
+    pub fn stats(&self) -> BumpStats {
+        let allocations = self.allocations.borrow();
+        BumpStats {
+            allocations: allocations.len(),
+            bytes: allocations.iter().map(|(_, layout)| layout.size()).sum(),
+            largest_layout: allocations.iter().map(|(_, layout)| *layout).max_by_key(|layout| layout.size()),
+        }
+    }
+
+    // Consumes the arena without freeing any of its blocks. This is a deliberate leak
+    // for an arena that lives until the process exits, where tearing it down is
+    // wasted work. Calling it on short-lived arenas leaks memory without bound.
+    pub fn leak_contents(self) {
+        mem::forget(self);
+    }
+}
+
+// Point-in-time view of a `Bump`, as returned by `Bump::stats`.
+#[derive(Clone, Debug)]
+pub struct BumpStats {
+    pub allocations: usize,
+    pub bytes: usize,
+    pub largest_layout: Option<Layout>,
 }
 
 impl Drop for Bump {
     fn drop(&mut self) {
-        for (ptr, layout) in self.allocations.get_mut().iter() {
+        self.reset();
+    }
+}
+
+// Thread-safe counterpart of `Bump`: the allocation list sits behind a `Mutex`
+// instead of a `RefCell`, so it can be shared between threads without racing on
+// the bookkeeping. Blocks come from the same backend as `Bump`'s, including the
+// guard-page one.
+pub struct SyncBump {
+    allocations: Mutex<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+// SAFETY: the raw pointers are only ever touched while holding the lock, and every
+// block they point to is owned by the arena until it is dropped.
+unsafe impl Send for SyncBump {}
+unsafe impl Sync for SyncBump {}
+
+impl SyncBump {
+    pub fn new() -> Self {
+        SyncBump {
+            allocations: Mutex::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+
+    pub fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        if let Some(block) = zero_size_block(layout) {
+            return block;
+        }
+        let ptr = unsafe { sys_alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        debug_assert!(
+            non_null_ptr.as_ptr() as usize & (layout.align() - 1) == 0,
+            "allocation at {:p} is not aligned to {}",
+            non_null_ptr.as_ptr(),
+            layout.align()
+        );
+        self.allocations.lock().unwrap().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+
+    pub fn allocation_count(&self) -> usize {
+        self.allocations.lock().unwrap().len()
+    }
+}
+
+impl Drop for SyncBump {
+    fn drop(&mut self) {
+        let allocations = self.allocations.get_mut().unwrap_or_else(|e| e.into_inner());
+        for (ptr, layout) in allocations.iter() {
             unsafe {
-                std::alloc::dealloc(ptr.as_ptr(), *layout);
+                sys_dealloc(ptr.as_ptr(), *layout);
             }
         }
     }
@@ -126,6 +441,59 @@ impl<'a, T> RawVec<'a, T> {
     fn cap(&self) -> usize { self.cap }
 }
 
+// Counts the elements a bulk write has initialized and stores the count into the
+// vector's `len` when dropped. If a `clone` panics part way through, unwinding
+// drops the guard, so `len` covers exactly the slots written so far: no
+// uninitialized slot is ever exposed to the vector's readers or to `truncate`.
+struct SetLenOnDrop<'a> {
+    len: &'a mut usize,
+    local_len: usize,
+}
+
+impl<'a> SetLenOnDrop<'a> {
+    #[inline]
+    fn new(len: &'a mut usize) -> Self {
+        SetLenOnDrop {
+            local_len: *len,
+            len,
+        }
+    }
+
+    #[inline]
+    fn increment_len(&mut self, increment: usize) {
+        self.local_len += increment;
+    }
+}
+
+impl Drop for SetLenOnDrop<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        *self.len = self.local_len;
+    }
+}
+
+// Tracks an in-place compaction: `0..write` holds the survivors, `write..read` is a
+// gap of moved-out or dropped slots, and `read..len` has not been examined yet. If
+// the key function or a destructor panics, dropping the guard shifts the unexamined
+// tail down over the gap and shortens the vector to match, so every element the
+// vector still counts is live and none is dropped twice.
+struct FillGapOnDrop<'a, 'bump, T> {
+    read: usize,
+    write: usize,
+    vec: &'a mut Vec<'bump, T>,
+}
+
+impl<T> Drop for FillGapOnDrop<'_, '_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            let p = self.vec.buf.ptr();
+            let tail = self.vec.len - self.read;
+            ptr::copy(p.add(self.read), p.add(self.write), tail);
+            self.vec.len = self.write + tail;
+        }
+    }
+}
+
 pub struct Vec<'bump, T: 'bump> {
     buf: RawVec<'bump, T>,
     len: usize,
@@ -139,13 +507,159 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
         }
     }
 
+    // Creates an empty vector with room for `capacity` elements, so the first
+    // `capacity` pushes do not grow the buffer. A capacity of 0 does not allocate.
+    pub fn with_capacity_in(bump: &'bump Bump, capacity: usize) -> Vec<'bump, T> {
+        let mut vec = Vec::new_in(bump);
+        vec.reserve(capacity);
+        vec
+    }
+
+    // Moves the elements of a heap `Vec` into a new arena vector, in order. Each
+    // element is moved exactly once; the heap buffer is freed when the consumed
+    // `std::vec::IntoIter` is dropped at the end of the call.
+    pub fn from_std_vec_in(bump: &'bump Bump, v: std::vec::Vec<T>) -> Vec<'bump, T> {
+        let mut vec = Vec::with_capacity_in(bump, v.len());
+        vec.extend(v);
+        vec
+    }
+
+    // Concatenates `slices` into a new arena vector. The total length is summed with
+    // `checked_add` first, so the buffer is allocated once and each slice is copied
+    // into it in bulk.
+    pub fn from_slices_in(bump: &'bump Bump, slices: &[&[T]]) -> Vec<'bump, T>
+    where
+        T: Copy,
+    {
+        let total = slices
+            .iter()
+            .try_fold(0usize, |total, s| total.checked_add(s.len()))
+            .unwrap_or_else(|| capacity_overflow());
+        let mut vec: Vec<T> = Vec::with_capacity_in(bump, total);
+        for s in slices {
+            unsafe {
+                ptr::copy_nonoverlapping(s.as_ptr(), vec.buf.ptr().add(vec.len), s.len());
+            }
+            vec.len += s.len();
+        }
+        vec
+    }
+
     #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+    #[inline] pub fn as_ptr(&self) -> *const T { self.buf.ptr() as *const T }
     #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
 
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { std::slice::from_raw_parts_mut(self.buf.ptr(), self.len) }
+    }
+
+    // Returns the element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    // Mutable counterpart of `get`. The reference borrows the vector mutably, so it
+    // cannot outlive a reallocation.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.as_mut_slice().get_mut(index)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    // Mutable counterpart of `iter`. The iterator borrows the vector mutably, so it
+    // cannot be pushed to (and reallocated) while the iterator is alive.
+    #[inline]
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.as_slice().contains(x)
+    }
+
+    // Searches a sorted vector for `x`. `Err` holds the index where `x` could be
+    // inserted to keep the vector sorted.
+    pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.as_slice().binary_search(x)
+    }
+
+    // Rotates the elements in place so that the element at `mid` becomes the first.
+    // Panics if `mid > len()`. The bound is checked here rather than in
+    // `<[T]>::rotate_left`, so that the panic points at the caller.
+    #[track_caller]
+    pub fn rotate_left(&mut self, mid: usize) {
+        assert!(mid <= self.len, "rotation point {} exceeds length {}", mid, self.len);
+        self.as_mut_slice().rotate_left(mid);
+    }
+
+    // Rotates the elements in place so that the last `k` elements come first.
+    // Panics if `k > len()`.
+    #[track_caller]
+    pub fn rotate_right(&mut self, k: usize) {
+        assert!(k <= self.len, "rotation count {} exceeds length {}", k, self.len);
+        self.as_mut_slice().rotate_right(k);
+    }
+
+    // Divides the elements into `[0, mid)` and `[mid, len)`. Panics if `mid > len()`.
+    #[inline]
+    #[track_caller]
+    pub fn split_at(&self, mid: usize) -> (&[T], &[T]) {
+        self.as_slice().split_at(mid)
+    }
+
+    // Mutable counterpart of `split_at`. The two halves do not overlap, so both can be
+    // written at once; together they borrow the vector mutably.
+    #[inline]
+    #[track_caller]
+    pub fn split_at_mut(&mut self, mid: usize) -> (&mut [T], &mut [T]) {
+        self.as_mut_slice().split_at_mut(mid)
+    }
+
     pub fn reserve(&mut self, additional: usize) {
         self.buf.reserve(self.len, additional);
     }
 
+    // Forces the length to `new_len`.
+    //
+    // The caller must ensure `new_len <= capacity()` and that every element in
+    // `old_len..new_len` has been initialized, e.g. through `spare_capacity_mut`.
+    #[inline]
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        self.len = new_len;
+    }
+
+    // The uninitialized tail of the buffer, between `len` and the capacity. Writes
+    // here are committed with `set_len`.
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.buf.ptr().add(self.len) as *mut MaybeUninit<T>,
+                self.buf.cap() - self.len,
+            )
+        }
+    }
+
     #[inline]
     pub fn push(&mut self, value: T) {
         if self.len == self.buf.cap() {
@@ -157,6 +671,142 @@ impl<'bump, T: 'bump> Vec<'bump, T> {
             self.len += 1;
         }
     }
+
+    // Consumes the vector without dropping its elements and returns them as a slice
+    // that lives as long as the arena. Unlike `std::vec::Vec::leak`, the slice is
+    // bounded by `'bump`, not `'static`, so the arena cannot be dropped while it is
+    // still in use.
+    pub fn leak(self) -> &'bump mut [T] {
+        let mut this = mem::ManuallyDrop::new(self);
+        unsafe { std::slice::from_raw_parts_mut(this.as_mut_ptr(), this.len) }
+    }
+
+    // The crate's name for `leak`: freezes the vector into a slice of exactly `len`
+    // elements. The spare capacity stays allocated in the arena but is not part of
+    // the slice. The elements are never dropped; the arena only frees their bytes.
+    pub fn into_bump_slice(self) -> &'bump mut [T] {
+        self.leak()
+    }
+
+    // Shortens the vector to `len` elements, dropping the rest. `len` is lowered
+    // before the tail is dropped, so a panicking destructor cannot cause a second
+    // drop of the same elements.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            unsafe {
+                let tail = std::slice::from_raw_parts_mut(self.buf.ptr().add(len), self.len - len);
+                self.len = len;
+                ptr::drop_in_place(tail);
+            }
+        }
+    }
+
+    // Removes all but the first of each run of consecutive elements with equal keys,
+    // dropping the removed elements. Survivors keep their order, as with
+    // `std::vec::Vec::dedup_by_key`.
+    pub fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        let len = self.len;
+        if len <= 1 {
+            return;
+        }
+        let p = self.buf.ptr();
+        let mut gap = FillGapOnDrop {
+            read: 1,
+            write: 1,
+            vec: self,
+        };
+        unsafe {
+            while gap.read < len {
+                let cur = p.add(gap.read);
+                let prev = p.add(gap.write - 1);
+                if key(&mut *cur) == key(&mut *prev) {
+                    // Step past the duplicate before dropping it, so a panicking
+                    // destructor leaves it in the gap rather than in the tail.
+                    gap.read += 1;
+                    ptr::drop_in_place(cur);
+                } else {
+                    ptr::copy(cur, p.add(gap.write), 1);
+                    gap.write += 1;
+                    gap.read += 1;
+                }
+            }
+            gap.vec.len = gap.write;
+            mem::forget(gap);
+        }
+    }
+
+    // Grows with clones of `value` or truncates to `new_len`. When growing, the last
+    // slot takes `value` itself, so only `new_len - len - 1` clones are made.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        let len = self.len;
+        if new_len > len {
+            let extra = new_len - len;
+            self.reserve(extra);
+            unsafe {
+                let mut dst = self.buf.ptr().add(len);
+                let mut local_len = SetLenOnDrop::new(&mut self.len);
+                for _ in 1..extra {
+                    ptr::write(dst, value.clone());
+                    dst = dst.add(1);
+                    local_len.increment_len(1);
+                }
+                ptr::write(dst, value);
+                local_len.increment_len(1);
+            }
+        } else {
+            self.truncate(new_len);
+        }
+    }
+
+    // Overwrites each of the `len` elements with a clone of `value`, dropping the old
+    // ones. The last element takes `value` itself. Spare capacity is not touched.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        if let Some((last, rest)) = self.as_mut_slice().split_last_mut() {
+            for slot in rest {
+                *slot = value.clone();
+            }
+            *last = value;
+        }
+    }
+
+    // Like `fill`, but each element is replaced with the next value returned by `f`.
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        for slot in self.as_mut_slice() {
+            *slot = f();
+        }
+    }
+
+    // Appends clones of every element of `other`, growing the buffer at most once.
+    // The clones are written straight into the spare capacity; `SetLenOnDrop` keeps
+    // `len` in step with them if one of the `clone` calls panics.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+        unsafe {
+            let mut dst = self.buf.ptr().add(self.len);
+            let mut local_len = SetLenOnDrop::new(&mut self.len);
+            for x in other {
+                ptr::write(dst, x.clone());
+                dst = dst.add(1);
+                local_len.increment_len(1);
+            }
+        }
+    }
 }
 
 impl<'bump, T: 'bump> Extend<T> for Vec<'bump, T> {
@@ -170,6 +820,76 @@ impl<'bump, T: 'bump> Extend<T> for Vec<'bump, T> {
     }
 }
 
+impl<'bump, T: 'bump + PartialEq> PartialEq for Vec<'bump, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<'bump, T: 'bump + Eq> Eq for Vec<'bump, T> {}
+
+// Lexicographic, like `std::vec::Vec`.
+impl<'bump, T: 'bump + PartialOrd> PartialOrd for Vec<'bump, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<'bump, T: 'bump + Ord> Ord for Vec<'bump, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<'bump, T: 'bump> AsRef<[T]> for Vec<'bump, T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'bump, T: 'bump> Borrow<[T]> for Vec<'bump, T> {
+    #[inline]
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+// Lets `write!` format straight into arena memory, like `std::vec::Vec<u8>`.
+impl<'bump> io::Write for Vec<'bump, u8> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.extend_from_slice(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// Borrowing iteration (`for x in &vec`). The returned iterator keeps `vec` borrowed,
+// so it cannot be reallocated while the iteration is in progress.
+impl<'a, 'bump, T: 'bump> IntoIterator for &'a Vec<'bump, T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> std::slice::Iter<'a, T> {
+        self.as_slice().iter()
+    }
+}
+
 // SECTION 2: VULNERABLE CODE
 
 // The IntoIter struct does not have a lifetime parameter `'bump`
@@ -222,24 +942,86 @@ impl<T> Iterator for IntoIter<T> {
         }
     }
 
+    // Skips `n` elements in O(1) instead of calling `next` `n` times. The skipped
+    // elements are dropped in place as one slice, after `ptr` has been moved past
+    // them, so a panicking destructor cannot cause them to be dropped again. If
+    // fewer than `n + 1` elements remain, all of them are dropped and `None` is
+    // returned.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let skip = n.min(self.size_hint().0);
+        unsafe {
+            let skipped = if mem::size_of::<T>() == 0 {
+                self.ptr = arith_offset(self.ptr as *const i8, skip as isize) as *mut T;
+                NonNull::dangling().as_ptr()
+            } else {
+                let skipped = self.ptr as *mut T;
+                self.ptr = self.ptr.add(skip);
+                skipped
+            };
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(skipped, skip));
+        }
+        self.next()
+    }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
         let exact = if mem::size_of::<T>() == 0 {
             (self.end as usize).wrapping_sub(self.ptr as usize)
         } else {
-            unsafe { offset_from(self.end, self.ptr) as usize }
+            // `ptr` and `end` point into the same buffer, with `ptr <= end`, which is
+            // what `offset_from` requires. It is undefined behavior for pointers into
+            // different allocations.
+            unsafe { self.end.offset_from(self.ptr) as usize }
         };
         (exact, Some(exact))
     }
 }
 
+impl<T> IntoIter<T> {
+    // Whether every element has been yielded. Also holds for zero-sized elements,
+    // where `ptr` and `end` count elements as byte offsets.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ptr == self.end
+    }
+
+    // Moves the elements not yielded yet into a heap `Vec`, in order. Each element is
+    // read out of the arena exactly once, so nothing is copied or dropped twice.
+    pub fn into_vec(self) -> std::vec::Vec<T> {
+        let mut out = std::vec::Vec::with_capacity(self.size_hint().0);
+        out.extend(self);
+        out
+    }
+}
+
 // SECTION 3: PROOF-OF-CONCEPT
 
+// Requests a block with the same layout as the one freed at `freed`, fills it with
+// 0x41 and reports whether the allocator handed back the same address. Whether it
+// does depends on the allocator in use: glibc's tcache serves a request from the
+// most recently freed chunk of that size, so there the pattern lands behind the
+// dangling pointer, but other allocators may place the block elsewhere. The block
+// is left allocated so that the pattern stays in place.
+fn force_reuse(freed: NonNull<u8>, layout: Layout) -> bool {
+    unsafe {
+        let block = std::alloc::alloc(layout);
+        if block.is_null() {
+            handle_alloc_error(layout);
+        }
+        ptr::write_bytes(block, 0x41, layout.size());
+        // Keep the optimizer from treating the never-read block as dead.
+        std::hint::black_box(block) == freed.as_ptr()
+    }
+}
+
 fn main() {
     // 1. Setup a vector allocated within a bump arena.
     let bump = Bump::new();
     let mut vec = Vec::new_in(&bump);
     vec.extend([0x01u8; 32]);
+    let buffer = NonNull::new(vec.as_mut_ptr()).unwrap();
+    let layout = Layout::array::<u8>(32).unwrap();
     let mut into_iter = vec.into_iter();
 
     // 2. Trigger BUG: Drop the bump arena, freeing the memory that backs the iterator.
@@ -247,11 +1029,10 @@ fn main() {
 
     // 3. Re-allocate the freed memory with a different data pattern.
     // This makes the UAF observable.
-    for _ in 0..100 {
-        // This allocation is likely to reuse the memory freed by dropping the first bump.
-        let reuse_bump = Bump::new();
-        let _reuse_alloc = reuse_bump.alloc([0x41u8; 64]);
-        // The reuse_bump is dropped here.
+    let reused = force_reuse(buffer, layout);
+    println!("Freed block handed out again: {}", reused);
+    if !reused {
+        println!("The allocator placed the new block elsewhere; the stale read may still see 0x01.");
     }
 
     // 4. Access the dangling iterator and verify data corruption.
@@ -262,4 +1043,1025 @@ fn main() {
 
     // If the memory was reused, the value will not be the original 0x01.
     assert_eq!(first_val, 0x01, "UAF CONFIRMED: memory was overwritten!");
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::Arc;
+
+    // Counts its own drops in a shared cell, so a test can check that every element
+    // is dropped exactly once.
+    #[derive(Clone)]
+    struct Counted<'a> {
+        value: u32,
+        drops: &'a Cell<usize>,
+    }
+
+    impl<'a> Counted<'a> {
+        fn new(value: u32, drops: &'a Cell<usize>) -> Self {
+            Counted { value, drops }
+        }
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    // Deterministic stand-in for random inputs: a 64-bit LCG, so a failing case
+    // reproduces on every run.
+    struct Lcg(u64);
+
+    impl Lcg {
+        fn next(&mut self) -> u32 {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (self.0 >> 33) as u32
+        }
+    }
+
+    fn values(vec: &Vec<Counted>) -> std::vec::Vec<u32> {
+        vec.iter().map(|c| c.value).collect()
+    }
+
+    // Runs `f` and returns the file and line it panicked at, or `None` if it returned.
+    // Only panics on the calling thread are recorded. Panics on other threads still
+    // reach the previous hook, so tests that panic in parallel report as usual.
+    fn panic_location<F: FnOnce()>(f: F) -> Option<(String, u32)> {
+        static HOOK: Mutex<()> = Mutex::new(());
+        let _serial = HOOK.lock().unwrap_or_else(|e| e.into_inner());
+        let thread = std::thread::current().id();
+        let seen = Arc::new(Mutex::new(None));
+        let previous = Arc::new(panic::take_hook());
+        {
+            let (seen, previous) = (seen.clone(), previous.clone());
+            panic::set_hook(Box::new(move |info| {
+                if std::thread::current().id() == thread {
+                    let location = info.location().unwrap();
+                    *seen.lock().unwrap() = Some((location.file().to_string(), location.line()));
+                } else {
+                    previous(info);
+                }
+            }));
+        }
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        drop(panic::take_hook());
+        match Arc::try_unwrap(previous) {
+            Ok(previous) => panic::set_hook(previous),
+            Err(_) => unreachable!("the recording hook was dropped above"),
+        }
+        let location = seen.lock().unwrap().take();
+        assert_eq!(result.is_err(), location.is_some());
+        location
+    }
+
+    // Like `Counted`, but `clone` panics once `clones_left` has run out.
+    struct Fragile<'a> {
+        drops: &'a Cell<usize>,
+        clones_left: &'a Cell<usize>,
+    }
+
+    impl<'a> Fragile<'a> {
+        fn new(drops: &'a Cell<usize>, clones_left: &'a Cell<usize>) -> Self {
+            Fragile { drops, clones_left }
+        }
+    }
+
+    impl Clone for Fragile<'_> {
+        fn clone(&self) -> Self {
+            match self.clones_left.get() {
+                0 => panic!("clone failed"),
+                n => self.clones_left.set(n - 1),
+            }
+            Fragile::new(self.drops, self.clones_left)
+        }
+    }
+
+    impl Drop for Fragile<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    // Counts its drop like `Counted`, then panics if `panics` is set.
+    struct PanicOnDrop<'a> {
+        value: u32,
+        panics: bool,
+        drops: &'a Cell<usize>,
+    }
+
+    impl Drop for PanicOnDrop<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+            if self.panics {
+                panic!("drop failed");
+            }
+        }
+    }
+
+    #[test]
+    fn borrowing_iteration_leaves_the_vec_usable() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3]);
+        let mut seen = std::vec::Vec::new();
+        for x in &vec {
+            seen.push(*x);
+        }
+        assert_eq!(seen, [1, 2, 3]);
+        vec.push(4);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn sync_bump_counts_allocations_from_every_thread() {
+        let bump = SyncBump::new();
+        std::thread::scope(|s| {
+            for t in 0..4u64 {
+                let bump = &bump;
+                s.spawn(move || {
+                    for i in 0..100 {
+                        assert_eq!(*bump.alloc(t * 100 + i), t * 100 + i);
+                    }
+                });
+            }
+        });
+        assert_eq!(bump.allocation_count(), 400);
+    }
+
+    #[test]
+    fn contains_finds_only_present_elements() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        assert!(!vec.contains(&1));
+        vec.extend([3, 1, 4]);
+        assert!(vec.contains(&1));
+        assert!(vec.contains(&4));
+        assert!(!vec.contains(&2));
+        assert_eq!(vec.iter().position(|&x| x == 4), Some(2));
+    }
+
+    #[test]
+    fn into_iter_round_trips_through_a_std_vec() {
+        let bump = Bump::new();
+        let mut rng = Lcg(107);
+        for len in 0..64 {
+            let input: std::vec::Vec<u32> = (0..len).map(|_| rng.next()).collect();
+            let mut vec: Vec<u32> = Vec::new_in(&bump);
+            vec.extend(input.iter().copied());
+            let output: std::vec::Vec<u32> = vec.into_iter().collect();
+            assert_eq!(output, input);
+        }
+    }
+
+    #[test]
+    fn into_iter_round_trip_drops_each_element_once() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let input: std::vec::Vec<Counted> = (0..10).map(|value| Counted::new(value, &drops)).collect();
+        let mut vec: Vec<Counted> = Vec::new_in(&bump);
+        vec.extend(input);
+        assert_eq!(drops.get(), 0);
+
+        let mut iter = vec.into_iter();
+        drop(iter.next());
+        let output: std::vec::Vec<Counted> = iter.collect();
+        assert_eq!(drops.get(), 1);
+        assert_eq!(output.iter().map(|c| c.value).collect::<std::vec::Vec<_>>(), (1..10).collect::<std::vec::Vec<_>>());
+        drop(output);
+        assert_eq!(drops.get(), 10);
+    }
+
+    // Yields what `I` yields but reports a fixed `size_hint`, however wrong.
+    struct Misreported<I>(I, usize);
+
+    impl<I: Iterator> Iterator for Misreported<I> {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<I::Item> {
+            self.0.next()
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (self.1, Some(self.1))
+        }
+    }
+
+    #[test]
+    fn alloc_iter_sizes_the_slice_by_what_the_iterator_yields() {
+        let bump = Bump::new();
+        let squares = bump.alloc_iter((0..5).map(|x| x * x));
+        assert_eq!(squares, &[0, 1, 4, 9, 16]);
+
+        let under = bump.alloc_iter(Misreported(0..5u32, 1));
+        assert_eq!(under, &[0, 1, 2, 3, 4]);
+        let over = bump.alloc_iter(Misreported(0..2u32, 100));
+        assert_eq!(over, &[0, 1]);
+        let empty = bump.alloc_iter(Misreported(0..0u32, 8));
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn resize_grows_with_the_fill_value_and_shrinks_by_dropping_the_tail() {
+        let bump = Bump::new();
+        let mut bytes: Vec<u8> = Vec::new_in(&bump);
+        bytes.extend([1, 2]);
+        bytes.resize(5, 0xff);
+        assert_eq!(bytes.as_slice(), &[1, 2, 0xff, 0xff, 0xff]);
+        bytes.resize(1, 0);
+        assert_eq!(bytes.as_slice(), &[1]);
+
+        let drops = Cell::new(0);
+        let mut vec: Vec<Counted> = Vec::new_in(&bump);
+        vec.resize(4, Counted::new(7, &drops));
+        // Three clones plus the value itself; nothing is dropped while growing.
+        assert_eq!(values(&vec), [7, 7, 7, 7]);
+        assert_eq!(drops.get(), 0);
+
+        // Shrinking drops the three tail elements and the unused fill value.
+        vec.resize(1, Counted::new(0, &drops));
+        assert_eq!(values(&vec), [7]);
+        assert_eq!(drops.get(), 4);
+
+        vec.truncate(0);
+        assert_eq!(drops.get(), 5);
+    }
+
+    fn checksum(bytes: impl AsRef<[u8]>) -> u32 {
+        bytes.as_ref().iter().map(|&b| u32::from(b)).sum()
+    }
+
+    #[test]
+    fn vec_passes_where_a_slice_is_expected() {
+        let bump = Bump::new();
+        let mut vec: Vec<u8> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3]);
+        assert_eq!(checksum(&vec), 6);
+        let borrowed: &[u8] = vec.borrow();
+        assert_eq!(borrowed, &[1, 2, 3]);
+        assert_eq!(checksum(vec), 6);
+    }
+
+    // Run with `cargo test --features guard-pages -- --ignored`. The block ends right
+    // before its guard page, so the write past the end is expected to kill the test
+    // process with SIGSEGV.
+    #[test]
+    #[ignore]
+    #[cfg(all(feature = "guard-pages", target_os = "linux"))]
+    fn writing_past_a_block_faults_on_the_guard_page() {
+        let bump = Bump::new();
+        let block = bump.alloc([0u8; 24]);
+        unsafe { ptr::write_volatile(block.as_mut_ptr().add(block.len()), 0xff) };
+        unreachable!("the write past the block did not fault");
+    }
+
+    #[test]
+    fn set_len_commits_writes_to_the_spare_capacity() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        vec.push(1);
+        vec.reserve(3);
+        let cap = vec.capacity();
+        let spare = vec.spare_capacity_mut();
+        assert_eq!(spare.len(), cap - 1);
+        for (i, slot) in spare.iter_mut().take(3).enumerate() {
+            slot.write(10 * (i as u32 + 2));
+        }
+        unsafe { vec.set_len(4) };
+        assert_eq!(vec.as_slice(), &[1, 20, 30, 40]);
+    }
+
+    #[test]
+    fn ordering_is_lexicographic_like_std_vec() {
+        let bump = Bump::new();
+        let from = |xs: &[i32]| {
+            let mut vec: Vec<i32> = Vec::new_in(&bump);
+            vec.extend(xs.iter().copied());
+            vec
+        };
+        let pairs: [(&[i32], &[i32]); 3] = [(&[1, 2], &[1, 3]), (&[1], &[1, 2]), (&[1, 3], &[1])];
+        for (a, b) in pairs {
+            let expected = a.to_vec().cmp(&b.to_vec());
+            assert_eq!(from(a).cmp(&from(b)), expected);
+            assert_eq!(from(a).partial_cmp(&from(b)), Some(expected));
+        }
+        assert!(from(&[1, 2]) < from(&[1, 3]));
+        assert!(from(&[1]) < from(&[1, 2]));
+    }
+
+    // The slice returned by `leak` borrows the arena, so the arena cannot be dropped
+    // while the slice is alive. Moving the arena into an inner scope is rejected:
+    //
+    //     let slice: &mut [u32] = {
+    //         let bump = Bump::new();
+    //         let mut vec: Vec<u32> = Vec::new_in(&bump); // <-- COMPILE ERROR: `bump` does not live long enough
+    //         vec.push(1);
+    //         vec.leak()
+    //     };
+    //     slice[0] = 2;
+    #[test]
+    fn leaked_slice_keeps_its_elements_while_the_arena_lives() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3]);
+        let slice = vec.leak();
+        slice[0] = 4;
+        assert_eq!(slice, &[4, 2, 3]);
+        assert_eq!(bump.allocation_layouts().len(), 1);
+    }
+
+    #[test]
+    fn stats_agree_with_allocation_layouts() {
+        let bump = Bump::new();
+        let empty = bump.stats();
+        assert_eq!((empty.allocations, empty.bytes, empty.largest_layout), (0, 0, None));
+
+        bump.alloc(1u8);
+        bump.alloc([0u64; 4]);
+        bump.alloc(2u32);
+        let layouts = bump.allocation_layouts();
+        let stats = bump.stats();
+        assert_eq!(layouts, [(1, 1), (32, 8), (4, 4)]);
+        assert_eq!(stats.allocations, layouts.len());
+        assert_eq!(stats.bytes, layouts.iter().map(|&(size, _)| size).sum::<usize>());
+        assert_eq!(stats.largest_layout, Some(Layout::new::<[u64; 4]>()));
+    }
+
+    #[test]
+    fn into_vec_moves_the_remaining_elements_in_order() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let mut vec: Vec<Counted> = Vec::new_in(&bump);
+        for value in 1..=5 {
+            vec.push(Counted::new(value, &drops));
+        }
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.next().map(|c| c.value), Some(1));
+        assert_eq!(iter.next().map(|c| c.value), Some(2));
+        assert_eq!(drops.get(), 2);
+
+        let rest = iter.into_vec();
+        assert_eq!(rest.iter().map(|c| c.value).collect::<std::vec::Vec<_>>(), [3, 4, 5]);
+        assert_eq!(rest.len(), 3);
+        assert!(rest.capacity() >= 3);
+        // Moving out of the arena drops nothing; dropping the heap `Vec` drops the rest.
+        assert_eq!(drops.get(), 2);
+        drop(rest);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn with_capacity_in_pushes_without_growing() {
+        let bump = Bump::new();
+        let empty: Vec<u32> = Vec::with_capacity_in(&bump, 0);
+        assert_eq!(empty.capacity(), 0);
+        assert_eq!(bump.stats().allocations, 0);
+
+        let mut vec: Vec<u32> = Vec::with_capacity_in(&bump, 8);
+        assert_eq!(vec.capacity(), 8);
+        assert_eq!(bump.stats().allocations, 1);
+        let buffer = vec.as_ptr();
+        for i in 0..8 {
+            vec.push(i);
+        }
+        assert_eq!(vec.as_ptr(), buffer);
+        assert_eq!(bump.stats().allocations, 1);
+        vec.push(8);
+        assert_eq!(bump.stats().allocations, 2);
+    }
+
+    #[test]
+    fn fill_and_fill_with_drop_each_replaced_element_once() {
+        let bump = Bump::new();
+        let old = Cell::new(0);
+        let new = Cell::new(0);
+        let mut vec: Vec<Counted> = Vec::with_capacity_in(&bump, 8);
+        for value in 0..3 {
+            vec.push(Counted::new(value, &old));
+        }
+
+        vec.fill(Counted::new(9, &new));
+        assert_eq!(values(&vec), [9, 9, 9]);
+        assert_eq!((old.get(), new.get()), (3, 0));
+        // Only `0..len` is written; the spare capacity is left alone.
+        assert_eq!((vec.len(), vec.capacity()), (3, 8));
+
+        let mut next = 10;
+        vec.fill_with(|| {
+            next += 1;
+            Counted::new(next, &old)
+        });
+        assert_eq!(values(&vec), [11, 12, 13]);
+        assert_eq!((old.get(), new.get()), (3, 3));
+
+        vec.truncate(0);
+        assert_eq!((old.get(), new.get()), (6, 3));
+    }
+
+    #[test]
+    fn iter_mut_updates_every_element_in_place() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3, 4]);
+        for x in vec.iter_mut() {
+            *x *= 2;
+        }
+        assert_eq!(vec.as_slice(), &[2, 4, 6, 8]);
+    }
+
+    // With capacity to spare, `push` stays on its fast path: it never reaches the
+    // panic machinery and never asks the arena for a block.
+    #[test]
+    fn push_into_reserved_capacity_neither_panics_nor_allocates() {
+        let bump = Bump::new();
+        let mut vec: Vec<u64> = Vec::new_in(&bump);
+        vec.reserve(64);
+        let blocks = bump.stats().allocations;
+        let buffer = vec.as_ptr();
+        assert_eq!(panic_location(|| (0..64).for_each(|i| vec.push(i))), None);
+        assert_eq!(vec.len(), 64);
+        assert_eq!(vec.as_ptr(), buffer);
+        assert_eq!(bump.stats().allocations, blocks);
+    }
+
+    #[test]
+    fn alloc_uninit_reads_back_what_was_written() {
+        let bump = Bump::new();
+        let slot = bump.alloc_uninit::<[u32; 4]>();
+        slot.write([1, 2, 3, 4]);
+        let value = unsafe { slot.assume_init_mut() };
+        value[3] = 40;
+        assert_eq!(*value, [1, 2, 3, 40]);
+        assert_eq!(bump.allocation_layouts(), [(16, 4)]);
+    }
+
+    #[test]
+    fn binary_search_reports_the_insertion_point_when_absent() {
+        let bump = Bump::new();
+        let mut vec: Vec<i32> = Vec::new_in(&bump);
+        vec.extend([-3, 0, 4, 4, 9]);
+        assert_eq!(vec.binary_search(&-3), Ok(0));
+        assert_eq!(vec.binary_search(&9), Ok(4));
+        assert!(matches!(vec.binary_search(&4), Ok(2) | Ok(3)));
+        assert_eq!(vec.binary_search(&-5), Err(0));
+        assert_eq!(vec.binary_search(&1), Err(2));
+        assert_eq!(vec.binary_search(&10), Err(5));
+    }
+
+    #[test]
+    fn write_formats_into_the_arena_buffer() {
+        use std::io::Write;
+
+        let bump = Bump::new();
+        let mut vec: Vec<u8> = Vec::new_in(&bump);
+        write!(vec, "{}", 42).unwrap();
+        assert_eq!(vec.as_slice(), b"42");
+        write!(vec, "-{:x}", 255).unwrap();
+        vec.flush().unwrap();
+        assert_eq!(vec.as_slice(), b"42-ff");
+    }
+
+    #[test]
+    fn zero_size_layouts_get_an_aligned_dangling_pointer() {
+        let bump = Bump::new();
+        for align in [1, 8, 64, 4096] {
+            let block = bump.alloc_layout(Layout::from_size_align(0, align).unwrap());
+            assert_eq!(block.as_ptr() as usize, align);
+        }
+        let empty: *mut [u32; 0] = bump.alloc([]);
+        assert_eq!(empty as usize, mem::align_of::<u32>());
+        // Nothing was taken from the backend, so there is nothing to free on drop.
+        assert!(bump.allocation_layouts().is_empty());
+
+        let sync = SyncBump::new();
+        assert_eq!(sync.alloc_layout(Layout::new::<[u64; 0]>()).as_ptr() as usize, 8);
+        assert_eq!(sync.allocation_count(), 0);
+    }
+
+    #[test]
+    fn a_panicking_clone_commits_only_the_written_elements() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let clones_left = Cell::new(usize::MAX);
+        let src: std::vec::Vec<Fragile> = (0..4).map(|_| Fragile::new(&drops, &clones_left)).collect();
+        let mut vec: Vec<Fragile> = Vec::new_in(&bump);
+        vec.extend_from_slice(&src[..1]);
+
+        // The third clone panics: the two written before it are kept.
+        clones_left.set(2);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| vec.extend_from_slice(&src)));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 3);
+        assert_eq!(drops.get(), 0);
+
+        // The second clone panics: one is kept, and the fill value is dropped while
+        // unwinding.
+        clones_left.set(1);
+        let fill = Fragile::new(&drops, &clones_left);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| vec.resize(8, fill)));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 4);
+        assert_eq!(drops.get(), 1);
+
+        // Every committed slot holds a live element, so each is dropped exactly once.
+        vec.truncate(0);
+        assert_eq!(drops.get(), 5);
+        drop(src);
+        assert_eq!(drops.get(), 9);
+    }
+
+    #[test]
+    fn alloc_slice_clone_clones_every_element() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let src = [Counted::new(1, &drops), Counted::new(2, &drops), Counted::new(3, &drops)];
+        let copy = bump.alloc_slice_clone(&src);
+        assert_eq!(copy.iter().map(|c| c.value).collect::<std::vec::Vec<_>>(), [1, 2, 3]);
+        assert_eq!(bump.stats().allocations, 1);
+        drop(src);
+        assert_eq!(drops.get(), 3);
+        unsafe { ptr::drop_in_place(copy) };
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn alloc_slice_clone_drops_the_cloned_prefix_on_panic() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let clones_left = Cell::new(2);
+        let src: std::vec::Vec<Fragile> = (0..4).map(|_| Fragile::new(&drops, &clones_left)).collect();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            bump.alloc_slice_clone(&src);
+        }));
+        assert!(result.is_err());
+        // The two clones made before the panic are dropped; `src` is untouched.
+        assert_eq!(drops.get(), 2);
+        drop(src);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn extend_keeps_len_in_step_when_the_iterator_panics() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let mut vec: Vec<Counted> = Vec::new_in(&bump);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            vec.extend((0..8).map(|value| {
+                if value == 5 {
+                    panic!("iterator failed");
+                }
+                Counted::new(value, &drops)
+            }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(values(&vec), [0, 1, 2, 3, 4]);
+        assert_eq!(drops.get(), 0);
+        vec.truncate(0);
+        assert_eq!(drops.get(), 5);
+    }
+
+    #[test]
+    fn rotations_move_elements_around_the_ends() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3, 4, 5]);
+        vec.rotate_left(2);
+        assert_eq!(vec.as_slice(), &[3, 4, 5, 1, 2]);
+        vec.rotate_right(2);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5]);
+        vec.rotate_left(5);
+        vec.rotate_right(0);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn rotate_left_past_the_end_panics() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3]);
+        let line = line!() + 1;
+        let location = panic_location(|| vec.rotate_left(4));
+        assert_eq!(location, Some((file!().to_string(), line)));
+    }
+
+    #[test]
+    fn rotate_right_past_the_end_panics() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3]);
+        let line = line!() + 1;
+        let location = panic_location(|| vec.rotate_right(4));
+        assert_eq!(location, Some((file!().to_string(), line)));
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn metrics_hook_reports_every_block_allocated_and_freed() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::atomic::Ordering::SeqCst;
+
+        static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+        static FREES: AtomicUsize = AtomicUsize::new(0);
+        static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+        fn count(event: AllocEvent) {
+            match event {
+                AllocEvent::Alloc { size, .. } => {
+                    ALLOCS.fetch_add(1, SeqCst);
+                    LIVE_BYTES.fetch_add(size, SeqCst);
+                }
+                AllocEvent::Free { size, .. } => {
+                    FREES.fetch_add(1, SeqCst);
+                    LIVE_BYTES.fetch_sub(size, SeqCst);
+                }
+            }
+        }
+
+        let bump = Bump::new();
+        bump.set_hook(count);
+        let mut vec: Vec<u64> = Vec::new_in(&bump);
+        vec.extend(0..100);
+        bump.alloc(7u32);
+        bump.alloc([0u8; 0]);
+        let stats = bump.stats();
+        assert_eq!((ALLOCS.load(SeqCst), FREES.load(SeqCst)), (stats.allocations, 0));
+        assert_eq!(LIVE_BYTES.load(SeqCst), stats.bytes);
+
+        drop(bump);
+        assert_eq!(FREES.load(SeqCst), ALLOCS.load(SeqCst));
+        assert_eq!(LIVE_BYTES.load(SeqCst), 0);
+    }
+
+    #[test]
+    fn get_and_get_mut_return_none_past_the_end() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        assert_eq!(vec.get(0), None);
+        vec.extend([1, 2, 3]);
+        assert_eq!(vec.get(0), Some(&1));
+        assert_eq!(vec.get(2), Some(&3));
+        assert_eq!(vec.get(3), None);
+        *vec.get_mut(1).unwrap() = 20;
+        assert_eq!(vec.get_mut(3), None);
+        assert_eq!(vec.as_slice(), &[1, 20, 3]);
+    }
+
+    #[test]
+    fn from_std_vec_in_moves_each_string_without_cloning_it() {
+        let bump = Bump::new();
+        let strings: std::vec::Vec<String> = ["alpha", "beta", "gamma"].iter().map(|s| s.to_string()).collect();
+        let buffers: std::vec::Vec<*const u8> = strings.iter().map(|s| s.as_ptr()).collect();
+        let mut vec = Vec::from_std_vec_in(&bump, strings);
+        assert_eq!(vec.as_slice(), &["alpha", "beta", "gamma"]);
+        // Each `String` still owns the heap buffer it was created with.
+        assert_eq!(vec.iter().map(|s| s.as_ptr()).collect::<std::vec::Vec<_>>(), buffers);
+        vec.truncate(0);
+    }
+
+    #[test]
+    fn from_std_vec_in_drops_each_element_once() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let heap: std::vec::Vec<Counted> = (0..4).map(|value| Counted::new(value, &drops)).collect();
+        let mut vec = Vec::from_std_vec_in(&bump, heap);
+        assert_eq!(values(&vec), [0, 1, 2, 3]);
+        assert_eq!(drops.get(), 0);
+        vec.truncate(0);
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn nth_drops_the_skipped_elements_and_returns_the_next() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let mut vec: Vec<Counted> = Vec::new_in(&bump);
+        for value in 0..6 {
+            vec.push(Counted::new(value, &drops));
+        }
+        let mut iter = vec.into_iter();
+        let fourth = iter.nth(3).unwrap();
+        assert_eq!(fourth.value, 3);
+        assert_eq!(drops.get(), 3);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        drop(fourth);
+
+        // Skipping past the end drops everything that is left.
+        assert!(iter.nth(5).is_none());
+        assert_eq!(drops.get(), 6);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn nth_drops_each_element_once_when_a_destructor_panics() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let mut vec: Vec<PanicOnDrop> = Vec::new_in(&bump);
+        for value in 0..6 {
+            vec.push(PanicOnDrop { value, panics: value == 1, drops: &drops });
+        }
+        let mut iter = vec.into_iter();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            iter.nth(3);
+        }));
+        assert!(result.is_err());
+        // All three skipped elements were dropped, including the ones after the
+        // panicking one, and the iterator has already moved past them.
+        assert_eq!(drops.get(), 3);
+        let rest: std::vec::Vec<u32> = iter.map(|e| e.value).collect();
+        assert_eq!(rest, [3, 4, 5]);
+        assert_eq!(drops.get(), 6);
+    }
+
+    #[test]
+    fn as_ptr_reads_back_the_pushed_elements() {
+        let bump = Bump::new();
+        let mut vec: Vec<u16> = Vec::new_in(&bump);
+        for i in 0..5 {
+            vec.push(i * 3);
+        }
+        let p = vec.as_ptr();
+        let read: std::vec::Vec<u16> = (0..vec.len()).map(|i| unsafe { *p.add(i) }).collect();
+        assert_eq!(read, [0, 3, 6, 9, 12]);
+        assert_eq!(p, vec.as_slice().as_ptr());
+    }
+
+    // The hand-rolled helper that `size_hint` used before it switched to the stable
+    // `<*const T>::offset_from`.
+    unsafe fn legacy_offset_from<T>(p: *const T, origin: *const T) -> isize {
+        let pointee_size = mem::size_of::<T>();
+        assert!(0 < pointee_size && pointee_size <= isize::MAX as usize);
+        isize::wrapping_sub(p as _, origin as _) / (pointee_size as isize)
+    }
+
+    fn legacy_len<T>(iter: &IntoIter<T>) -> usize {
+        if mem::size_of::<T>() == 0 {
+            (iter.end as usize).wrapping_sub(iter.ptr as usize)
+        } else {
+            unsafe { legacy_offset_from(iter.end, iter.ptr) as usize }
+        }
+    }
+
+    #[test]
+    fn offset_from_matches_the_legacy_length_computation() {
+        let bump = Bump::new();
+
+        let empty: Vec<u64> = Vec::new_in(&bump);
+        let iter = empty.into_iter();
+        assert_eq!(iter.size_hint(), (legacy_len(&iter), Some(0)));
+
+        let mut vec: Vec<u64> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3, 4, 5]);
+        let mut iter = vec.into_iter();
+        for remaining in (0..=5).rev() {
+            assert_eq!(iter.size_hint(), (legacy_len(&iter), Some(remaining)));
+            assert_eq!(legacy_len(&iter), remaining);
+            iter.next();
+        }
+
+        let mut zsts: Vec<()> = Vec::new_in(&bump);
+        zsts.extend([(), (), ()]);
+        let mut iter = zsts.into_iter();
+        iter.next();
+        assert_eq!(iter.size_hint(), (legacy_len(&iter), Some(2)));
+        assert_eq!(legacy_len(&iter), 2);
+    }
+
+    #[test]
+    fn capacity_doubles_as_the_vec_grows() {
+        let bump = Bump::new();
+        let mut vec: Vec<u8> = Vec::new_in(&bump);
+        assert_eq!(vec.capacity(), 0);
+        let mut caps = std::vec::Vec::new();
+        for i in 0..20 {
+            vec.push(i);
+            if caps.last() != Some(&vec.capacity()) {
+                caps.push(vec.capacity());
+            }
+        }
+        assert_eq!(caps, [1, 2, 4, 8, 16, 32]);
+        // A reservation past the doubled size is met exactly.
+        vec.reserve(100);
+        assert_eq!(vec.capacity(), 120);
+    }
+
+    #[test]
+    fn is_empty_tracks_the_remaining_elements() {
+        let bump = Bump::new();
+        let mut vec: Vec<u8> = Vec::new_in(&bump);
+        assert!(vec.is_empty());
+        vec.push(1);
+        assert!(!vec.is_empty());
+        let mut iter = vec.into_iter();
+        assert!(!iter.is_empty());
+        iter.next();
+        assert!(iter.is_empty());
+
+        let mut zsts: Vec<()> = Vec::new_in(&bump);
+        assert!(zsts.is_empty());
+        zsts.extend([(), ()]);
+        assert!(!zsts.is_empty());
+        let mut iter = zsts.into_iter();
+        iter.next();
+        assert!(!iter.is_empty());
+        iter.next();
+        assert!(iter.is_empty());
+    }
+
+    #[test]
+    fn over_aligned_allocations_pass_the_alignment_check() {
+        #[derive(Clone, Copy)]
+        #[repr(align(64))]
+        struct CacheLine(u8);
+
+        let bump = Bump::new();
+        for i in 0..8 {
+            let line = bump.alloc(CacheLine(i));
+            assert_eq!(line as *mut CacheLine as usize % 64, 0);
+            assert_eq!(line.0, i);
+        }
+        let mut vec: Vec<CacheLine> = Vec::new_in(&bump);
+        vec.extend((0..5).map(CacheLine));
+        assert_eq!(vec.as_ptr() as usize % 64, 0);
+        assert_eq!(bump.allocation_layouts().last(), Some(&(5 * 64, 64)));
+    }
+
+    #[test]
+    fn dedup_by_key_drops_each_removed_element_once() {
+        let bump = Bump::new();
+        let drops = Cell::new(0);
+        let mut vec: Vec<Counted> = Vec::new_in(&bump);
+        for value in [1, 1, 2, 2, 2, 3, 1, 1] {
+            vec.push(Counted::new(value, &drops));
+        }
+        vec.dedup_by_key(|c| c.value);
+        assert_eq!(values(&vec), [1, 2, 3, 1]);
+        assert_eq!(drops.get(), 4);
+        vec.truncate(0);
+        assert_eq!(drops.get(), 8);
+
+        // A panicking key function leaves the survivors and the unexamined tail in
+        // place, with the gap closed, so nothing leaks and nothing is dropped twice.
+        drops.set(0);
+        for value in [1, 1, 2, 3, 3, 4] {
+            vec.push(Counted::new(value, &drops));
+        }
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            vec.dedup_by_key(|c| {
+                if c.value == 3 {
+                    panic!("key failed");
+                }
+                c.value
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(values(&vec), [1, 2, 3, 3, 4]);
+        assert_eq!(drops.get(), 1);
+        vec.truncate(0);
+        assert_eq!(drops.get(), 6);
+    }
+
+    // Hand-rolled stand-in for a stateful property test: the same random sequence of
+    // operations is applied to a bump `Vec` and a `std::vec::Vec`, and the two must
+    // agree on contents after every step and on drop counts throughout.
+    #[test]
+    fn random_operations_match_std_vec() {
+        for seed in 0..32 {
+            let bump = Bump::new();
+            let drops = Cell::new(0);
+            let std_drops = Cell::new(0);
+            let mut rng = Lcg(seed);
+            let mut vec: Vec<Counted> = Vec::new_in(&bump);
+            let mut model: std::vec::Vec<Counted> = std::vec::Vec::new();
+
+            for step in 0..200 {
+                let value = rng.next() % 8;
+                let len = vec.len();
+                match rng.next() % 9 {
+                    0 | 1 => {
+                        vec.push(Counted::new(value, &drops));
+                        model.push(Counted::new(value, &std_drops));
+                    }
+                    2 => {
+                        let new_len = rng.next() as usize % (len + 2);
+                        vec.truncate(new_len);
+                        model.truncate(new_len);
+                    }
+                    3 => {
+                        let new_len = rng.next() as usize % (len + 8);
+                        vec.resize(new_len, Counted::new(value, &drops));
+                        model.resize(new_len, Counted::new(value, &std_drops));
+                    }
+                    4 => {
+                        let n = rng.next() % 4;
+                        vec.extend((0..n).map(|i| Counted::new(value + i, &drops)));
+                        model.extend((0..n).map(|i| Counted::new(value + i, &std_drops)));
+                    }
+                    5 => {
+                        let n = rng.next() % 4;
+                        let slice: std::vec::Vec<Counted> = (0..n).map(|i| Counted::new(value + i, &drops)).collect();
+                        let std_slice: std::vec::Vec<Counted> = (0..n).map(|i| Counted::new(value + i, &std_drops)).collect();
+                        vec.extend_from_slice(&slice);
+                        model.extend_from_slice(&std_slice);
+                    }
+                    6 => {
+                        vec.dedup_by_key(|c| c.value / 2);
+                        model.dedup_by_key(|c| c.value / 2);
+                    }
+                    7 => {
+                        vec.fill(Counted::new(value, &drops));
+                        model.fill(Counted::new(value, &std_drops));
+                    }
+                    _ => {
+                        let mid = rng.next() as usize % (len + 1);
+                        if rng.next() & 1 == 0 {
+                            vec.rotate_left(mid);
+                            model.rotate_left(mid);
+                        } else {
+                            vec.rotate_right(mid);
+                            model.rotate_right(mid);
+                        }
+                    }
+                }
+                let values: std::vec::Vec<u32> = vec.iter().map(|c| c.value).collect();
+                let expected: std::vec::Vec<u32> = model.iter().map(|c| c.value).collect();
+                assert_eq!(values, expected, "seed {} step {}", seed, step);
+                assert_eq!(drops.get(), std_drops.get(), "seed {} step {}", seed, step);
+            }
+
+            vec.truncate(0);
+            drop(model);
+            assert_eq!(drops.get(), std_drops.get(), "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn split_at_mut_halves_are_disjoint() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3, 4, 5]);
+        let base = vec.as_ptr();
+        let (left, right) = vec.split_at_mut(2);
+        assert_eq!((left.len(), right.len()), (2, 3));
+        assert_eq!(left.as_ptr(), base);
+        assert_eq!(right.as_ptr(), unsafe { base.add(2) });
+        left[1] = 20;
+        right[0] = 30;
+        assert_eq!(vec.as_slice(), &[1, 20, 30, 4, 5]);
+        assert_eq!(vec.split_at(5), (&[1, 20, 30, 4, 5][..], &[][..]));
+    }
+
+    #[test]
+    fn split_at_mut_past_the_end_panics() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::new_in(&bump);
+        vec.extend([1, 2, 3]);
+        let line = line!() + 2;
+        let location = panic_location(|| {
+            vec.split_at_mut(4);
+        });
+        assert_eq!(location, Some((file!().to_string(), line)));
+    }
+
+    #[test]
+    fn high_water_mark_survives_reset_until_cleared() {
+        let mut bump = Bump::new();
+        bump.alloc([0u8; 64]);
+        bump.alloc([0u8; 32]);
+        assert_eq!((bump.allocated_bytes(), bump.high_water_mark()), (96, 96));
+
+        bump.reset();
+        assert_eq!(bump.stats().allocations, 0);
+        assert_eq!((bump.allocated_bytes(), bump.high_water_mark()), (0, 96));
+
+        bump.alloc([0u8; 16]);
+        assert_eq!((bump.allocated_bytes(), bump.high_water_mark()), (16, 96));
+
+        bump.reset_high_water();
+        assert_eq!(bump.high_water_mark(), 16);
+        bump.alloc([0u8; 8]);
+        assert_eq!(bump.high_water_mark(), 24);
+    }
+
+    #[test]
+    fn into_bump_slice_keeps_exactly_len_elements() {
+        let bump = Bump::new();
+        let mut vec: Vec<u32> = Vec::with_capacity_in(&bump, 8);
+        vec.extend([1, 2, 3]);
+        let buffer = vec.as_ptr();
+        let slice = vec.into_bump_slice();
+        assert_eq!(slice, &[1, 2, 3]);
+        assert_eq!(slice.as_ptr(), buffer);
+        // The spare capacity stays allocated in the arena but is not part of the slice.
+        assert_eq!(bump.stats().bytes, 8 * mem::size_of::<u32>());
+    }
+
+    #[test]
+    fn from_slices_in_concatenates_with_one_allocation() {
+        let bump = Bump::new();
+        let vec = Vec::from_slices_in(&bump, &[&[1u16, 2][..], &[][..], &[3, 4, 5][..]]);
+        assert_eq!(vec.as_slice(), &[1, 2, 3, 4, 5]);
+        assert_eq!(vec.capacity(), 5);
+        assert_eq!(bump.allocation_layouts(), [(5 * mem::size_of::<u16>(), mem::align_of::<u16>())]);
+    }
+}
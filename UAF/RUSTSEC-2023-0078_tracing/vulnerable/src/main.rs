@@ -47,6 +47,12 @@ impl<T> Instrumented<T> {
             span: Span::new(),
         }
     }
+
+    // Raw pointer to the wrapped value, for C code that needs its address. It is only
+    // valid while this `Instrumented` is alive and has not been moved.
+    pub fn inner_ptr(&self) -> *const T {
+        &*self.inner as *const T
+    }
 }
 
 
@@ -0,0 +1,233 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::ops::Index;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+
+    // Returns a block to the system allocator before the arena itself is dropped.
+    // `RawVec` uses this to release the buffer it abandons on reallocation, so the
+    // freed block can be handed out again by the very next allocation.
+    unsafe fn dealloc(&self, ptr: NonNull<u8>, layout: Layout) {
+        let mut allocations = self.allocations.borrow_mut();
+        if let Some(i) = allocations.iter().position(|&(p, _)| p == ptr) {
+            allocations.swap_remove(i);
+            std::alloc::dealloc(ptr.as_ptr(), layout);
+        }
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    // Moves the first `len` elements into a fresh block of `new_cap` elements and
+    // frees the old block. Every pointer into the old buffer dangles afterwards.
+    fn realloc(&mut self, len: usize, new_cap: usize) {
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, len);
+                let old_layout = Layout::array::<T>(self.cap).unwrap_or_else(|_| capacity_overflow());
+                self.a.dealloc(self.ptr.cast(), old_layout);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        self.realloc(len, new_cap);
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    pub fn shrink_to_fit(&mut self, len: usize) {
+        if len > 0 && len < self.cap {
+            self.realloc(len, len);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.buf.shrink_to_fit(self.len);
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+impl<'bump, T: 'bump> Index<usize> for Vec<'bump, T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &T {
+        &self.as_slice()[index]
+    }
+}
+
+impl<'bump, T: 'bump> Extend<T> for Vec<'bump, T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for t in iter {
+            self.push(t);
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+// The raw-pointer accessor `element_ptr` is gone. Elements are only reachable through
+// `Index` and `as_slice`, which return references tied to a shared borrow of the `Vec`.
+// While such a reference is alive, `reserve` and `shrink_to_fit` (which take
+// `&mut self`) cannot be called, so the buffer can never be reallocated under it.
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup a full vector in the arena: 32 elements of 0x01, capacity 32.
+    let bump = Bump::new();
+    let mut vec = Vec::new_in(&bump);
+    vec.extend([0x01u8; 32]);
+    assert_eq!(vec.capacity(), 32);
+
+    // 2. Keep a reference to the first element through the safe `Index` API.
+    let first = &vec[0];
+
+    // 3. THIS NOW CAUSES A COMPILE-TIME ERROR.
+    // `first` holds a shared borrow of `vec`, so the mutable borrow needed by
+    // `reserve` is rejected and the reallocation cannot happen.
+    vec.reserve(64); // <-- COMPILE ERROR: cannot borrow `vec` as mutable because it is also borrowed as immutable
+
+    // 4. This section is now unreachable due to the compile error above.
+    let _canary = bump.alloc([0x41u8; 32]);
+
+    // 5. This use of `first` is what causes the borrow checker to report the error.
+    let first_val = *first;
+    println!("Read through element reference: 0x{:02x}", first_val);
+
+    assert_eq!(first_val, 0x01);
+}
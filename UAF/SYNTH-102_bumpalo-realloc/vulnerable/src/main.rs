@@ -0,0 +1,270 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::ops::Index;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+
+    // Returns a block to the system allocator before the arena itself is dropped.
+    // `RawVec` uses this to release the buffer it abandons on reallocation, so the
+    // freed block can be handed out again by the very next allocation.
+    unsafe fn dealloc(&self, ptr: NonNull<u8>, layout: Layout) {
+        let mut allocations = self.allocations.borrow_mut();
+        if let Some(i) = allocations.iter().position(|&(p, _)| p == ptr) {
+            allocations.swap_remove(i);
+            std::alloc::dealloc(ptr.as_ptr(), layout);
+        }
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    // Moves the first `len` elements into a fresh block of `new_cap` elements and
+    // frees the old block. Every pointer into the old buffer dangles afterwards.
+    fn realloc(&mut self, len: usize, new_cap: usize) {
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, len);
+                let old_layout = Layout::array::<T>(self.cap).unwrap_or_else(|_| capacity_overflow());
+                self.a.dealloc(self.ptr.cast(), old_layout);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        self.realloc(len, new_cap);
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    pub fn shrink_to_fit(&mut self, len: usize) {
+        if len > 0 && len < self.cap {
+            self.realloc(len, len);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.buf.shrink_to_fit(self.len);
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+impl<'bump, T: 'bump> Index<usize> for Vec<'bump, T> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &T {
+        &self.as_slice()[index]
+    }
+}
+
+impl<'bump, T: 'bump> Extend<T> for Vec<'bump, T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for t in iter {
+            self.push(t);
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+// `element_ptr` hands out a raw pointer to an element. Unlike `&vec[index]`, the
+// pointer carries no borrow of the `Vec`, so nothing stops the caller from calling
+// `reserve` or `shrink_to_fit` while still holding it.
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn element_ptr(&self, index: usize) -> *const T {
+        assert!(index < self.len, "index out of bounds");
+        unsafe { self.buf.ptr().add(index) }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup a full vector in the arena: 32 elements of 0x01, capacity 32.
+    let bump = Bump::new();
+    let mut vec = Vec::new_in(&bump);
+    vec.extend([0x01u8; 32]);
+    assert_eq!(vec.capacity(), 32);
+
+    // 2. Keep a raw pointer to the first element.
+    let first = vec.element_ptr(0);
+
+    // 3. Trigger BUG: grow the vector. The elements move to a 64-byte block and the
+    // old 32-byte block is freed, leaving `first` dangling.
+    vec.reserve(64);
+
+    // 4. Re-allocate a block of the same size with a different pattern (canary).
+    // The allocator hands back the block freed in step 3.
+    let _canary = bump.alloc([0x41u8; 32]);
+
+    // 5. Read through the stale pointer and verify data corruption.
+    // The original value was 0x01. If we read the canary (0x41), the
+    // Use-After-Free is confirmed.
+    let first_val = unsafe { *first };
+    println!("Read through stale element pointer: 0x{:02x}", first_val);
+    println!("Value still in the vector:          0x{:02x}", vec[0]);
+
+    assert_eq!(first_val, 0x01, "UAF CONFIRMED: memory was overwritten!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::panic;
+    use std::sync::Mutex;
+
+    static LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+    #[test]
+    fn out_of_bounds_index_reports_the_callers_location() {
+        let bump = Bump::new();
+        let mut vec = Vec::new_in(&bump);
+        vec.push(1u8);
+
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(|info| {
+            let location = info.location().unwrap();
+            *LOCATION.lock().unwrap() = Some((location.file().to_string(), location.line()));
+        }));
+        let expected_line = line!() + 1;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| vec[1]));
+        panic::set_hook(previous);
+
+        assert!(result.is_err());
+        let (file, line) = LOCATION.lock().unwrap().take().unwrap();
+        assert_eq!((file.as_str(), line), (file!(), expected_line));
+    }
+}
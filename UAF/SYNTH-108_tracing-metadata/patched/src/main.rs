@@ -0,0 +1,80 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+// Minimal definition for `tracing::Metadata`
+#[derive(Debug, Clone)]
+pub struct Metadata<'a> {
+    _name: &'a str,
+}
+
+impl<'a> Metadata<'a> {
+    pub fn name(&self) -> &'a str {
+        self._name
+    }
+}
+
+static METADATA: Metadata<'static> = Metadata { _name: "poc_span" };
+
+// Minimal definition for `tracing::span::Inner`
+#[derive(Debug, Clone)]
+pub struct Inner;
+
+// Minimal definition for `tracing::Span`
+// Like the real type, a span only ever refers to `'static` metadata.
+#[derive(Clone, Debug)]
+pub struct Span {
+    _inner: Option<Inner>,
+    _meta: Option<&'static Metadata<'static>>,
+}
+
+impl Span {
+    pub fn new() -> Self {
+        Self::new_with(&METADATA)
+    }
+
+    pub fn new_with(meta: &'static Metadata<'static>) -> Self {
+        Self {
+            _inner: Some(Inner),
+            _meta: Some(meta),
+        }
+    }
+
+    pub fn metadata(&self) -> Option<&'static Metadata<'static>> {
+        self._meta
+    }
+}
+
+
+// SECTION 2: PATCHED CODE
+
+// The lifetime requirement is stated in the signature instead of being transmuted
+// away: only metadata that really lives for `'static` can be registered.
+pub fn register_callsite(meta: &'static Metadata<'static>) -> &'static Metadata<'static> {
+    meta
+}
+
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup metadata whose name lives in a heap buffer of 32 bytes.
+    let name = String::from("dynamically_named_callsite_span!");
+    assert_eq!(name.len(), 32);
+    let meta = Metadata { _name: &name };
+
+    // 2. THIS NOW CAUSES A COMPILE-TIME ERROR.
+    // `register_callsite` requires `&'static Metadata<'static>`, but `meta` borrows
+    // the local `name` and lives only until the end of `main`.
+    let span = Span::new_with(register_callsite(&meta)); // <-- COMPILE ERROR: `name`/`meta` does not live long enough
+    println!("Span name before drop:  {:?}", span.metadata().unwrap().name());
+
+    // 3. This section is now unreachable due to the compile error above.
+    drop(name);
+    let _canary = Box::new([0x41u8; 32]);
+
+    // 4. The span can only ever name `'static` data, so this read is always valid.
+    let name = span.metadata().unwrap().name();
+    println!("Span name after drop:   {:02x?}", name.as_bytes());
+
+    assert_eq!(name, "dynamically_named_callsite_span!");
+}
@@ -0,0 +1,90 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::mem;
+
+// Minimal definition for `tracing::Metadata`
+#[derive(Debug, Clone)]
+pub struct Metadata<'a> {
+    _name: &'a str,
+}
+
+impl<'a> Metadata<'a> {
+    pub fn name(&self) -> &'a str {
+        self._name
+    }
+}
+
+static METADATA: Metadata<'static> = Metadata { _name: "poc_span" };
+
+// Minimal definition for `tracing::span::Inner`
+#[derive(Debug, Clone)]
+pub struct Inner;
+
+// Minimal definition for `tracing::Span`
+// Like the real type, a span only ever refers to `'static` metadata.
+#[derive(Clone, Debug)]
+pub struct Span {
+    _inner: Option<Inner>,
+    _meta: Option<&'static Metadata<'static>>,
+}
+
+impl Span {
+    pub fn new() -> Self {
+        Self::new_with(&METADATA)
+    }
+
+    pub fn new_with(meta: &'static Metadata<'static>) -> Self {
+        Self {
+            _inner: Some(Inner),
+            _meta: Some(meta),
+        }
+    }
+
+    pub fn metadata(&self) -> Option<&'static Metadata<'static>> {
+        self._meta
+    }
+}
+
+
+// SECTION 2: VULNERABLE CODE
+
+// Registers a callsite's metadata so that spans can refer to it. The signature
+// accepts metadata of any lifetime and silently promotes it to `'static` with a
+// transmute, so a span can keep a `_name` that points into a buffer the caller
+// frees later.
+pub fn register_callsite<'a>(meta: &'a Metadata<'a>) -> &'static Metadata<'static> {
+    unsafe { mem::transmute::<&'a Metadata<'a>, &'static Metadata<'static>>(meta) }
+}
+
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup metadata whose name lives in a heap buffer of 32 bytes.
+    let name = String::from("dynamically_named_callsite_span!");
+    assert_eq!(name.len(), 32);
+    let meta = Metadata { _name: &name };
+
+    // 2. Register it and create a span that refers to it as `'static`.
+    let span = Span::new_with(register_callsite(&meta));
+    println!("Span name before drop:  {:?}", span.metadata().unwrap().name());
+
+    // 3. Trigger BUG: free the name's buffer while the span still refers to it.
+    drop(name);
+
+    // 4. Re-allocate a buffer of the same size with a different pattern (canary).
+    // `black_box` keeps the optimizer from removing the never-read buffer.
+    let _canary = std::hint::black_box(Box::new([0x41u8; 32]));
+
+    // 5. Read the span's name through the dangling `_name` field.
+    let dangling = span.metadata().unwrap().name();
+    println!("Span name after drop:   {:02x?}", dangling.as_bytes());
+
+    // The freed bytes need not be valid UTF-8, so compare them as bytes.
+    assert_eq!(
+        dangling.as_bytes(),
+        b"dynamically_named_callsite_span!",
+        "UAF CONFIRMED: the metadata name was overwritten!"
+    );
+}
@@ -0,0 +1,122 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::marker::PhantomPinned;
+use std::mem::{self, ManuallyDrop};
+use std::pin::Pin;
+use std::ptr;
+
+// Minimal definition for `tracing::Metadata`
+#[derive(Debug, Clone)]
+pub struct Metadata<'a> {
+    _name: &'a str,
+}
+
+static METADATA: Metadata<'static> = Metadata { _name: "poc_span" };
+
+// Minimal definition for `tracing::span::Inner`
+#[derive(Debug, Clone)]
+pub struct Inner;
+
+// Minimal definition for `tracing::Span`
+#[derive(Clone, Debug)]
+pub struct Span {
+    _inner: Option<Inner>,
+    _meta: Option<&'static Metadata<'static>>,
+}
+
+impl Span {
+    pub fn new() -> Self {
+        Self {
+            _inner: Some(Inner),
+            _meta: Some(&METADATA),
+        }
+    }
+}
+
+// Minimal definition for `tracing::Instrumented<T>`
+// The real type uses `pin-project`: `inner` is structurally pinned, `span` is not.
+#[derive(Debug)]
+pub struct Instrumented<T> {
+    inner: ManuallyDrop<T>,
+    span: Span,
+}
+
+impl<T> Instrumented<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner: ManuallyDrop::new(inner),
+            span: Span::new(),
+        }
+    }
+}
+
+// A minimal self-referential value, standing in for an `async fn` state machine
+// that holds a pointer into its own storage. Once `init` has run it must never
+// move again, which is why it is `!Unpin`.
+pub struct SelfRef {
+    buf: [u8; 16],
+    ptr: *const u8,
+    _pin: PhantomPinned,
+}
+
+impl SelfRef {
+    pub fn new(buf: [u8; 16]) -> Self {
+        Self {
+            buf,
+            ptr: ptr::null(),
+            _pin: PhantomPinned,
+        }
+    }
+
+    // Points `ptr` at the value's own buffer.
+    pub fn init(self: Pin<&mut Self>) {
+        let this = unsafe { self.get_unchecked_mut() };
+        this.ptr = this.buf.as_ptr();
+    }
+
+    // Reads the buffer back through the self-pointer.
+    pub fn read(&self) -> [u8; 16] {
+        assert!(!self.ptr.is_null(), "SelfRef used before init");
+        unsafe { ptr::read(self.ptr as *const [u8; 16]) }
+    }
+}
+
+
+// SECTION 2: PATCHED CODE
+
+impl<T> Instrumented<T> {
+    // Projects a pinned `Instrumented` onto its fields, keeping `inner` pinned.
+    // `span` is not structurally pinned, so a plain reference to it is fine.
+    pub fn project(self: Pin<&mut Self>) -> (Pin<&mut T>, &Span) {
+        // SAFETY: `inner` is never moved out of a pinned `Instrumented`: it is only
+        // exposed as `Pin<&mut T>`, and `Instrumented` has no `Drop` impl that could
+        // move it.
+        let this = unsafe { self.get_unchecked_mut() };
+        (unsafe { Pin::new_unchecked(&mut *this.inner) }, &this.span)
+    }
+}
+
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup a pinned, instrumented self-referential value.
+    let mut instrumented = Box::pin(Instrumented::new(SelfRef::new(*b"pinned-state-ok!")));
+    {
+        let (inner, _span) = instrumented.as_mut().project();
+        inner.init();
+    }
+
+    // 2. THIS NOW CAUSES A COMPILE-TIME ERROR.
+    // `project` returns `Pin<&mut SelfRef>`. Getting a `&mut SelfRef` out of it
+    // requires `SelfRef: Unpin`, which `PhantomPinned` rules out.
+    let (inner, span) = instrumented.as_mut().project();
+    let moved = mem::replace(&mut *inner, SelfRef::new(*b"AAAAAAAAAAAAAAAA")); // <-- COMPILE ERROR: cannot borrow data in dereference of `Pin<&mut SelfRef>` as mutable
+    println!("Projected span: {:?}", span);
+
+    // 3. This section is now unreachable due to the compile error above.
+    let seen = moved.read();
+    println!("Read through self-pointer:  {:?}", String::from_utf8_lossy(&seen));
+    assert_eq!(&seen, b"pinned-state-ok!");
+}
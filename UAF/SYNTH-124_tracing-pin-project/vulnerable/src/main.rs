@@ -0,0 +1,122 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::marker::PhantomPinned;
+use std::mem::{self, ManuallyDrop};
+use std::pin::Pin;
+use std::ptr;
+
+// Minimal definition for `tracing::Metadata`
+#[derive(Debug, Clone)]
+pub struct Metadata<'a> {
+    _name: &'a str,
+}
+
+static METADATA: Metadata<'static> = Metadata { _name: "poc_span" };
+
+// Minimal definition for `tracing::span::Inner`
+#[derive(Debug, Clone)]
+pub struct Inner;
+
+// Minimal definition for `tracing::Span`
+#[derive(Clone, Debug)]
+pub struct Span {
+    _inner: Option<Inner>,
+    _meta: Option<&'static Metadata<'static>>,
+}
+
+impl Span {
+    pub fn new() -> Self {
+        Self {
+            _inner: Some(Inner),
+            _meta: Some(&METADATA),
+        }
+    }
+}
+
+// Minimal definition for `tracing::Instrumented<T>`
+// The real type uses `pin-project`: `inner` is structurally pinned, `span` is not.
+#[derive(Debug)]
+pub struct Instrumented<T> {
+    inner: ManuallyDrop<T>,
+    span: Span,
+}
+
+impl<T> Instrumented<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner: ManuallyDrop::new(inner),
+            span: Span::new(),
+        }
+    }
+}
+
+// A minimal self-referential value, standing in for an `async fn` state machine
+// that holds a pointer into its own storage. Once `init` has run it must never
+// move again, which is why it is `!Unpin`.
+pub struct SelfRef {
+    buf: [u8; 16],
+    ptr: *const u8,
+    _pin: PhantomPinned,
+}
+
+impl SelfRef {
+    pub fn new(buf: [u8; 16]) -> Self {
+        Self {
+            buf,
+            ptr: ptr::null(),
+            _pin: PhantomPinned,
+        }
+    }
+
+    // Points `ptr` at the value's own buffer.
+    pub fn init(self: Pin<&mut Self>) {
+        let this = unsafe { self.get_unchecked_mut() };
+        this.ptr = this.buf.as_ptr();
+    }
+
+    // Reads the buffer back through the self-pointer.
+    pub fn read(&self) -> [u8; 16] {
+        assert!(!self.ptr.is_null(), "SelfRef used before init");
+        unsafe { ptr::read(self.ptr as *const [u8; 16]) }
+    }
+}
+
+
+// SECTION 2: VULNERABLE CODE
+
+impl<T> Instrumented<T> {
+    // Projects a pinned `Instrumented` onto its fields. `inner` is handed out as a
+    // plain `&mut T`, dropping the pin on a structurally pinned field: safe code can
+    // now `mem::replace`/`mem::swap` the wrapped value out of its pinned location.
+    pub fn project(self: Pin<&mut Self>) -> (&mut T, &Span) {
+        let this = unsafe { self.get_unchecked_mut() };
+        (&mut *this.inner, &this.span)
+    }
+}
+
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup a pinned, instrumented self-referential value.
+    let mut instrumented = Box::pin(Instrumented::new(SelfRef::new(*b"pinned-state-ok!")));
+    {
+        let (inner, _span) = instrumented.as_mut().project();
+        // Safe here: `instrumented` is pinned in its box and the value has not moved.
+        unsafe { Pin::new_unchecked(inner) }.init();
+    }
+
+    // 2. Trigger BUG: move the initialized value out of its pinned slot. Its
+    // self-pointer still targets the slot, which now holds a different value.
+    let (inner, span) = instrumented.as_mut().project();
+    let moved = mem::replace(inner, SelfRef::new(*b"AAAAAAAAAAAAAAAA"));
+    println!("Projected span: {:?}", span);
+
+    // 3. Verify corruption. Reading through the moved value's self-pointer should
+    // return its own buffer; it returns the replacement's bytes instead.
+    let seen = moved.read();
+    println!("Moved value's own buffer:   {:?}", String::from_utf8_lossy(&moved.buf));
+    println!("Read through self-pointer:  {:?}", String::from_utf8_lossy(&seen));
+    assert_eq!(&seen, b"pinned-state-ok!", "PIN VIOLATION CONFIRMED: the self-pointer still targets the old slot!");
+}
@@ -0,0 +1,242 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, self.cap);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+    #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+// Like the crate's `Vec`, dropping the vector drops its elements in place. The buffer
+// itself belongs to the arena and is released when the `Bump` is dropped.
+impl<'bump, T: 'bump> Drop for Vec<'bump, T> {
+    fn drop(&mut self) {
+        unsafe {
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.buf.ptr(), self.len));
+        }
+    }
+}
+
+// Ids seen by `Token::drop`, in drop order.
+const MAX_TOKENS: usize = 4;
+static DROPPED_IDS: [AtomicU64; MAX_TOKENS] = [const { AtomicU64::new(0) }; MAX_TOKENS];
+static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+// An element whose destructor reads its own fields, as most real destructors do.
+pub struct Token {
+    id: u64,
+    _tag: u64,
+}
+
+impl Token {
+    pub fn new(id: u64) -> Self {
+        Token { id, _tag: !id }
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        let slot = DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        if slot < MAX_TOKENS {
+            DROPPED_IDS[slot].store(self.id, Ordering::SeqCst);
+        }
+    }
+}
+
+pub fn dropped_ids() -> std::vec::Vec<u64> {
+    let count = DROP_COUNT.load(Ordering::SeqCst).min(MAX_TOKENS);
+    DROPPED_IDS[..count].iter().map(|id| id.load(Ordering::SeqCst)).collect()
+}
+
+// SECTION 2: VULNERABLE CODE
+
+// A self-contained arena holding both the `Bump` and a vector that lives in it.
+// Rust drops struct fields in declaration order, so `bump` is dropped first and
+// frees the vector's buffer; `tokens` is dropped afterwards and runs every
+// `Token::drop` on memory that has already been returned to the allocator.
+pub struct Arena {
+    bump: Box<Bump>,
+    tokens: Vec<'static, Token>,
+}
+
+impl Arena {
+    pub fn new() -> Self {
+        let bump = Box::new(Bump::new());
+        // The vector borrows the boxed arena through a raw pointer: the box keeps the
+        // `Bump` at a stable address, but nothing ties the borrow to the field order.
+        let bump_ref: &'static Bump = unsafe { &*(&*bump as *const Bump) };
+        Arena {
+            tokens: Vec::new_in(bump_ref),
+            bump,
+        }
+    }
+
+    pub fn push(&mut self, token: Token) {
+        self.tokens.push(token);
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup an arena holding four tokens in a single 64-byte buffer.
+    let mut arena = Arena::new();
+    arena.tokens.reserve(MAX_TOKENS);
+    for id in 1..=MAX_TOKENS as u64 {
+        arena.push(Token::new(id));
+    }
+    println!("Arena holds {} tokens in {} blocks.", arena.tokens.len(), arena.bump.allocations.borrow().len());
+
+    // 2. Trigger BUG: drop the arena. The `Bump` frees the buffer before the tokens'
+    // destructors read from it; the allocator reuses the freed block's first bytes
+    // for its own metadata, which clobbers the first token.
+    drop(arena);
+
+    // 3. Verify the ids seen by the destructors.
+    let ids = dropped_ids();
+    println!("Ids seen while dropping: {:x?}", ids);
+    assert_eq!(ids, [1, 2, 3, 4], "UAF CONFIRMED: tokens were dropped after their arena was freed!");
+}
@@ -0,0 +1,104 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk.
+// `used` is the bump cursor: the offset of the first byte no allocation owns yet.
+// Rewinding it to 0 hands the whole chunk out again.
+pub struct Bump {
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            chunk,
+            used: Cell::new(0),
+        }
+    }
+
+    // `alloc` takes `&self` but returns `&mut T`. That is only sound because every
+    // call is handed a region no other allocation overlaps.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+}
+
+impl Bump {
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        let end = match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => end,
+            _ => handle_alloc_error(layout),
+        };
+        self.used.set(end);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+
+    // Bytes currently handed out from the chunk.
+    pub fn allocated_bytes(&self) -> usize {
+        self.used.get()
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+impl Bump {
+    // Rewinds the cursor so the chunk can be reused. Taking `&mut self` requires that
+    // no reference returned by `alloc` is still alive, since they all borrow `self`.
+    pub fn reset(&mut self) {
+        self.used.set(0);
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup an allocation and keep a reference to it.
+    let mut bump = Bump::new();
+    let config = bump.alloc(*b"config=keep-this");
+    println!("Before reset: {:?} ({} bytes in use)", String::from_utf8_lossy(config), bump.allocated_bytes());
+
+    // 2. Attempt to reset the arena while `config` is still alive.
+    bump.reset(); // <-- COMPILE ERROR: cannot borrow `bump` as mutable because it is also borrowed as immutable
+
+    // This section is now unreachable due to the compile error above.
+    let _scratch = bump.alloc([0x41u8; 16]);
+    println!("After reset:  {:?}", String::from_utf8_lossy(config));
+    assert_eq!(config, b"config=keep-this");
+    println!("Verification successful: the reset was rejected while a reference was alive.");
+}
@@ -0,0 +1,118 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+
+// Minimal definition for `tracing::Metadata`
+#[derive(Debug, Clone)]
+pub struct Metadata<'a> {
+    _name: &'a str,
+}
+
+static METADATA: Metadata<'static> = Metadata { _name: "poc_span" };
+
+// Minimal definition for `tracing::span::Inner`
+#[derive(Debug, Clone)]
+pub struct Inner;
+
+// Minimal definition for `tracing::Span`
+#[derive(Clone, Debug)]
+pub struct Span {
+    _inner: Option<Inner>,
+    _meta: Option<&'static Metadata<'static>>,
+}
+
+impl Span {
+    pub fn new() -> Self {
+        Self {
+            _inner: Some(Inner),
+            _meta: Some(&METADATA),
+        }
+    }
+}
+
+// Minimal definition for `tracing::Instrumented<T>`
+// This struct mirrors the memory layout of the original without depending on `pin-project`.
+#[derive(Debug, Clone)]
+pub struct Instrumented<T> {
+    inner: ManuallyDrop<T>,
+    span: Span,
+}
+
+// A constructor is added here to facilitate the PoC setup, along with the fixed
+// `into_inner` from tracing 0.1.40.
+impl<T> Instrumented<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner: ManuallyDrop::new(inner),
+            span: Span::new(),
+        }
+    }
+
+    /// Consumes the `Instrumented`, returning the wrapped type.
+    ///
+    /// Note that this drops the span.
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+        let span: *const Span = &this.span;
+        let inner: *const ManuallyDrop<T> = &this.inner;
+        let _span = unsafe { span.read() };
+        let inner = unsafe { inner.read() };
+        ManuallyDrop::into_inner(inner)
+    }
+}
+
+
+// SECTION 2: PATCHED CODE
+
+// The address of an `Instrumented`'s wrapped value, borrowed from it. While an
+// `InnerPtr` is alive its `Instrumented` cannot be moved, consumed or dropped.
+pub struct InnerPtr<'a, T> {
+    ptr: *const T,
+    _owner: PhantomData<&'a T>,
+}
+
+impl<T> InnerPtr<'_, T> {
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+}
+
+impl<T> Instrumented<T> {
+    // Hands out the wrapped value's address tied to a borrow of `self`.
+    pub fn inner_ptr(&self) -> InnerPtr<'_, T> {
+        InnerPtr {
+            ptr: &*self.inner as *const T,
+            _owner: PhantomData,
+        }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+// Stands in for a C function that reads the value behind a pointer it was given.
+// `black_box` makes the pointer as opaque to the optimizer as a real FFI call would,
+// so the read is not moved ahead of the canary allocation.
+fn ffi_read(ptr: *const [u8; 32]) -> [u8; 32] {
+    unsafe { *std::hint::black_box(ptr) }
+}
+
+fn main() {
+    // 1. Setup a heap-allocated, instrumented 32-byte value and register its address
+    // with "C code", which keeps it.
+    let instrumented = Box::new(Instrumented::new(*b"registered-with-the-c-library!!!"));
+    let registered = instrumented.inner_ptr();
+
+    // 2. Attempt to consume the `Instrumented` while its address is still registered.
+    let value = {
+        let boxed = instrumented; // <-- COMPILE ERROR: cannot move out of `instrumented` because it is borrowed
+        (*boxed).into_inner()
+    };
+
+    // This section is now unreachable due to the compile error above.
+    let _canary = Box::new([0x41u8; std::mem::size_of::<Instrumented<[u8; 32]>>()]);
+    let seen = ffi_read(registered.as_ptr());
+    assert_eq!(seen, value);
+    println!("Verification successful: the Instrumented could not be consumed while its address was registered.");
+}
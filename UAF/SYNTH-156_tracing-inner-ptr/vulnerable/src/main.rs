@@ -0,0 +1,109 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::mem::ManuallyDrop;
+
+// Minimal definition for `tracing::Metadata`
+#[derive(Debug, Clone)]
+pub struct Metadata<'a> {
+    _name: &'a str,
+}
+
+static METADATA: Metadata<'static> = Metadata { _name: "poc_span" };
+
+// Minimal definition for `tracing::span::Inner`
+#[derive(Debug, Clone)]
+pub struct Inner;
+
+// Minimal definition for `tracing::Span`
+#[derive(Clone, Debug)]
+pub struct Span {
+    _inner: Option<Inner>,
+    _meta: Option<&'static Metadata<'static>>,
+}
+
+impl Span {
+    pub fn new() -> Self {
+        Self {
+            _inner: Some(Inner),
+            _meta: Some(&METADATA),
+        }
+    }
+}
+
+// Minimal definition for `tracing::Instrumented<T>`
+// This struct mirrors the memory layout of the original without depending on `pin-project`.
+#[derive(Debug, Clone)]
+pub struct Instrumented<T> {
+    inner: ManuallyDrop<T>,
+    span: Span,
+}
+
+// A constructor is added here to facilitate the PoC setup, along with the fixed
+// `into_inner` from tracing 0.1.40.
+impl<T> Instrumented<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner: ManuallyDrop::new(inner),
+            span: Span::new(),
+        }
+    }
+
+    /// Consumes the `Instrumented`, returning the wrapped type.
+    ///
+    /// Note that this drops the span.
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+        let span: *const Span = &this.span;
+        let inner: *const ManuallyDrop<T> = &this.inner;
+        let _span = unsafe { span.read() };
+        let inner = unsafe { inner.read() };
+        ManuallyDrop::into_inner(inner)
+    }
+}
+
+
+// SECTION 2: VULNERABLE CODE
+
+impl<T> Instrumented<T> {
+    // Raw pointer to the wrapped value, for C code that needs its address. It is only
+    // valid while this `Instrumented` is alive and has not been moved, but nothing
+    // enforces that: the pointer carries no borrow, so the `Instrumented` can be
+    // consumed by `into_inner` (or dropped) while the pointer is still stored.
+    pub fn inner_ptr(&self) -> *const T {
+        &*self.inner as *const T
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+// Stands in for a C function that reads the value behind a pointer it was given.
+// `black_box` makes the pointer as opaque to the optimizer as a real FFI call would,
+// so the read is not moved ahead of the canary allocation.
+fn ffi_read(ptr: *const [u8; 32]) -> [u8; 32] {
+    unsafe { *std::hint::black_box(ptr) }
+}
+
+fn main() {
+    // 1. Setup a heap-allocated, instrumented 32-byte value and register its address
+    // with "C code", which keeps it.
+    let instrumented = Box::new(Instrumented::new(*b"registered-with-the-c-library!!!"));
+    let registered = instrumented.inner_ptr();
+
+    // 2. Trigger BUG: consume the `Instrumented`. The value is moved out and the box
+    // that held it is freed, while the C side still holds `registered`.
+    let value = {
+        let boxed = instrumented;
+        (*boxed).into_inner()
+    };
+
+    // 3. Re-allocate a block of the same size with a different pattern (canary).
+    // `black_box` keeps the optimizer from removing the never-read block.
+    let _canary = std::hint::black_box(Box::new([0x41u8; std::mem::size_of::<Instrumented<[u8; 32]>>()]));
+
+    // 4. The C side reads through its stored pointer.
+    let seen = ffi_read(registered);
+    println!("Value moved out:      {:?}", String::from_utf8_lossy(&value));
+    println!("Read by the C side:   {:?}", String::from_utf8_lossy(&seen));
+    assert_eq!(seen, value, "UAF CONFIRMED: the registered pointer outlived its Instrumented!");
+}
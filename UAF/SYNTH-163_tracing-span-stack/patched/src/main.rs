@@ -0,0 +1,100 @@
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::cell::RefCell;
+
+// Minimal definition for `tracing::span::Inner`
+// Owned by its span and freed when the span is dropped.
+#[derive(Debug)]
+pub struct Inner {
+    id: u64,
+    _fields: [u64; 3],
+}
+
+// Minimal definition for `tracing::Span`
+#[derive(Debug)]
+pub struct Span {
+    inner: Box<Inner>,
+}
+
+impl Span {
+    pub fn new(id: u64) -> Self {
+        Self {
+            inner: Box::new(Inner {
+                id,
+                _fields: [0; 3],
+            }),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.inner.id
+    }
+
+    // Enters the span, making it the current span until the returned guard is dropped.
+    pub fn enter(&self) -> Entered<'_> {
+        let inner: *const Inner = &*self.inner;
+        SPAN_STACK.with(|stack| stack.borrow_mut().push(inner));
+        Entered { span: self }
+    }
+}
+
+// The per-thread stack of entered spans, innermost last. As in a subscriber's
+// registry, it stores plain pointers: it does not keep the spans alive.
+thread_local! {
+    static SPAN_STACK: RefCell<Vec<*const Inner>> = const { RefCell::new(Vec::new()) };
+}
+
+// Returns the id of the current (innermost entered) span.
+pub fn current_span_id() -> Option<u64> {
+    SPAN_STACK.with(|stack| stack.borrow().last().map(|&inner| unsafe { (*inner).id }))
+}
+
+// Minimal definition for `tracing::span::Entered`
+// The guard borrows its span, so the span cannot be dropped while it is entered.
+#[derive(Debug)]
+pub struct Entered<'a> {
+    span: &'a Span,
+}
+
+
+// SECTION 2: PATCHED CODE
+
+// Exiting removes the guard's own entry, searching from the top of the stack,
+// instead of popping blindly. Guards dropped out of order then exit the span they
+// entered, and the stack only ever holds spans that are still entered - and so,
+// through the guards' borrows, still alive.
+impl Drop for Entered<'_> {
+    fn drop(&mut self) {
+        let inner: *const Inner = &*self.span.inner;
+        SPAN_STACK.with(|stack| {
+            let mut stack = stack.borrow_mut();
+            if let Some(pos) = stack.iter().rposition(|&entry| entry == inner) {
+                stack.remove(pos);
+            }
+        });
+    }
+}
+
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup two spans and enter them, outer first.
+    let outer = Span::new(1);
+    let inner = Span::new(2);
+    let outer_guard = outer.enter();
+    let inner_guard = inner.enter();
+
+    // 2. Exit the outer span before the inner one, then close it.
+    drop(outer_guard);
+    drop(outer);
+
+    // 3. Verify the current span. Only `inner` is still entered, so it must be current.
+    let current = current_span_id();
+    println!("Inner span id:   {}", inner_guard.span.id());
+    println!("Current span id: {:x?}", current);
+    assert_eq!(current, Some(2), "UAF CONFIRMED: the current span points to a freed span!");
+    drop(inner_guard);
+    assert_eq!(current_span_id(), None);
+    println!("Verification successful: out-of-order exits leave the stack consistent.");
+}
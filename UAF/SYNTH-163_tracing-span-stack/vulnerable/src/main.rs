@@ -0,0 +1,96 @@
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::cell::RefCell;
+
+// Minimal definition for `tracing::span::Inner`
+// Owned by its span and freed when the span is dropped.
+#[derive(Debug)]
+pub struct Inner {
+    id: u64,
+    _fields: [u64; 3],
+}
+
+// Minimal definition for `tracing::Span`
+#[derive(Debug)]
+pub struct Span {
+    inner: Box<Inner>,
+}
+
+impl Span {
+    pub fn new(id: u64) -> Self {
+        Self {
+            inner: Box::new(Inner {
+                id,
+                _fields: [0; 3],
+            }),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.inner.id
+    }
+
+    // Enters the span, making it the current span until the returned guard is dropped.
+    pub fn enter(&self) -> Entered<'_> {
+        let inner: *const Inner = &*self.inner;
+        SPAN_STACK.with(|stack| stack.borrow_mut().push(inner));
+        Entered { span: self }
+    }
+}
+
+// The per-thread stack of entered spans, innermost last. As in a subscriber's
+// registry, it stores plain pointers: it does not keep the spans alive.
+thread_local! {
+    static SPAN_STACK: RefCell<Vec<*const Inner>> = const { RefCell::new(Vec::new()) };
+}
+
+// Returns the id of the current (innermost entered) span.
+pub fn current_span_id() -> Option<u64> {
+    SPAN_STACK.with(|stack| stack.borrow().last().map(|&inner| unsafe { (*inner).id }))
+}
+
+// Minimal definition for `tracing::span::Entered`
+// The guard borrows its span, so the span cannot be dropped while it is entered.
+#[derive(Debug)]
+pub struct Entered<'a> {
+    span: &'a Span,
+}
+
+
+// SECTION 2: VULNERABLE CODE
+
+// Exiting assumes guards are dropped in strict LIFO order and pops whatever entry is
+// on top. Nothing enforces that order: two guards are independent values, and
+// dropping the outer one first removes the *inner* span's entry and leaves the
+// outer span on the stack. Once the outer span is dropped, the current span is a
+// pointer to freed memory.
+impl Drop for Entered<'_> {
+    fn drop(&mut self) {
+        SPAN_STACK.with(|stack| {
+            stack.borrow_mut().pop();
+        });
+    }
+}
+
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup two spans and enter them, outer first.
+    let outer = Span::new(1);
+    let inner = Span::new(2);
+    let outer_guard = outer.enter();
+    let inner_guard = inner.enter();
+
+    // 2. Trigger BUG: exit the outer span before the inner one, then close it.
+    // The guard's borrow of `outer` ended with the guard, so this compiles.
+    drop(outer_guard);
+    drop(outer);
+
+    // 3. Verify the current span. Only `inner` is still entered, so it must be current.
+    let current = current_span_id();
+    println!("Inner span id:   {}", inner_guard.span.id());
+    println!("Current span id: {:x?}", current);
+    assert_eq!(current, Some(2), "UAF CONFIRMED: the current span points to a freed span!");
+    drop(inner_guard);
+}
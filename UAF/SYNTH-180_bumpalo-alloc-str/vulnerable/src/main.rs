@@ -0,0 +1,111 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::ptr::{self, NonNull};
+use std::{slice, str};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    // Copies `src` into the arena and returns the copy. The string borrows the arena,
+    // so it cannot outlive it.
+    pub fn alloc_str(&self, src: &str) -> &mut str {
+        let layout = Layout::for_value(src.as_bytes());
+        unsafe {
+            let dst = self.alloc_layout(layout).as_ptr();
+            ptr::copy_nonoverlapping(src.as_ptr(), dst, src.len());
+            str::from_utf8_unchecked_mut(slice::from_raw_parts_mut(dst, src.len()))
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        // A zero-size block needs no memory, and the system allocator must not be
+        // called with a size of 0.
+        if layout.size() == 0 {
+            return unsafe { NonNull::new_unchecked(layout.align() as *mut u8) };
+        }
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+// Interns `name` in the arena and returns it as `&'static str`, so it can be stored
+// in long-lived tables without carrying the arena's lifetime around. The transmute
+// erases `'bump`: nothing stops the arena from being dropped while the string is
+// still in use, and the string then points into freed memory.
+pub fn intern<'bump>(bump: &'bump Bump, name: &str) -> &'static str {
+    let s: &'bump str = bump.alloc_str(name);
+    unsafe { std::mem::transmute::<&'bump str, &'static str>(s) }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup an arena and intern a metric name in it.
+    let bump = Bump::new();
+    let name = intern(&bump, "service.request.latency_ms");
+    println!("Interned:        {:?}", name);
+
+    // 2. Trigger BUG: drop the arena while the interned name is still in use.
+    drop(bump);
+
+    // 3. Read the name through the dangling `&'static str`. The bytes are copied out
+    // right away, before any other allocation can reuse the freed block.
+    let mut seen = [0u8; 26];
+    seen.copy_from_slice(name.as_bytes());
+    println!("Read after drop: {:?}", String::from_utf8_lossy(&seen));
+    println!("Bytes:           {:02x?}", seen);
+    assert_eq!(&seen, b"service.request.latency_ms", "UAF CONFIRMED: the interned string was freed with the arena!");
+}
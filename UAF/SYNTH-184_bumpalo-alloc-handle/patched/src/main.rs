@@ -0,0 +1,176 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::{self, NonNull};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk.
+// `used` is the bump cursor: the offset of the first byte no allocation owns yet.
+// Rewinding it to 0 hands the whole chunk out again. `generation` counts the
+// resets, so anything recorded before a reset can tell that it is out of date.
+// It is 64 bits wide so that it cannot wrap around: a 32-bit counter comes back
+// to an old value after 2^32 resets, and a handle that old would validate again.
+// `id` is unique to each arena, so a value recorded against one arena is not
+// mistaken for a value in another.
+pub struct Bump {
+    id: u64,
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+    generation: u64,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+static NEXT_ARENA_ID: AtomicU64 = AtomicU64::new(0);
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            id: NEXT_ARENA_ID.fetch_add(1, Ordering::Relaxed),
+            chunk,
+            used: Cell::new(0),
+            generation: 0,
+        }
+    }
+
+    // `alloc` takes `&self` but returns `&mut T`. That is only sound because every
+    // call is handed a region no other allocation overlaps.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+}
+
+impl Bump {
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        let end = match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => end,
+            _ => handle_alloc_error(layout),
+        };
+        self.used.set(end);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+
+    // Bytes currently handed out from the chunk.
+    pub fn allocated_bytes(&self) -> usize {
+        self.used.get()
+    }
+
+    // Rewinds the cursor so the chunk can be reused. Taking `&mut self` requires that
+    // no reference returned by `alloc` is still alive, since they all borrow `self`.
+    pub fn reset(&mut self) {
+        self.used.set(0);
+        self.generation += 1;
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// SECTION 2: PATCHED CODE
+
+// A handle to a value in the arena: its offset into the chunk, plus the id and
+// generation of the arena it was allocated in. Unlike a reference from `alloc`, a
+// handle does not borrow the arena, so it does not block `reset`.
+#[derive(Debug)]
+pub struct AllocHandle<T> {
+    arena: u64,
+    offset: usize,
+    generation: u64,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for AllocHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AllocHandle<T> {}
+
+impl Bump {
+    pub fn alloc_handle<T>(&self, val: T) -> AllocHandle<T> {
+        let p = self.alloc_layout(Layout::new::<T>());
+        unsafe { ptr::write(p.as_ptr() as *mut T, val) };
+        AllocHandle {
+            arena: self.id,
+            offset: p.as_ptr() as usize - self.chunk.as_ptr() as usize,
+            generation: self.generation,
+            _marker: PhantomData,
+        }
+    }
+
+    // Resolves a handle. A handle from another arena, or from an earlier generation
+    // of this one, is rejected before its offset is looked at: its offset says
+    // nothing about what this chunk holds now.
+    pub fn get_handle<T>(&self, handle: AllocHandle<T>) -> Option<&T> {
+        if handle.arena != self.id || handle.generation != self.generation {
+            return None;
+        }
+        if handle.offset + mem::size_of::<T>() > self.used.get() {
+            return None;
+        }
+        unsafe { Some(&*(self.chunk.as_ptr().add(handle.offset) as *const T)) }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup a session token stored behind a handle.
+    let mut bump = Bump::new();
+    let token = bump.alloc_handle(*b"session=alice-42");
+    println!("Before reset: {:?}", bump.get_handle(token).map(|t| String::from_utf8_lossy(t)));
+
+    // 2. Reset the arena while the handle is still held.
+    bump.reset();
+
+    // 3. Allocate again. The new value is placed where the token lived.
+    let fresh = bump.alloc_handle(*b"session=mallory!");
+
+    // 4. Resolve the old handle. It must be rejected or still see its own value.
+    let seen = bump.get_handle(token);
+    println!("After reset:  {:?}", seen.map(|t| String::from_utf8_lossy(t)));
+    assert!(
+        seen.is_none_or(|t| t == b"session=alice-42"),
+        "UAF CONFIRMED: a stale handle resolved to another allocation!"
+    );
+    assert!(seen.is_none());
+    assert_eq!(bump.get_handle(fresh), Some(b"session=mallory!"));
+
+    // 5. A handle is only valid in the arena that issued it, even when the other
+    //    arena has a value of the same type at the same offset and generation.
+    let other = Bump::new();
+    other.alloc_handle(*b"session=eve-1337");
+    assert!(other.get_handle(token).is_none());
+    println!("Verification successful: stale and foreign handles were rejected.");
+}
@@ -0,0 +1,152 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk.
+// `used` is the bump cursor: the offset of the first byte no allocation owns yet.
+// Rewinding it to 0 hands the whole chunk out again. `generation` counts the
+// resets, so anything recorded before a reset can tell that it is out of date.
+// It is 64 bits wide so that it cannot wrap around: a 32-bit counter comes back
+// to an old value after 2^32 resets, and a handle that old would validate again.
+pub struct Bump {
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+    generation: u64,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            chunk,
+            used: Cell::new(0),
+            generation: 0,
+        }
+    }
+
+    // `alloc` takes `&self` but returns `&mut T`. That is only sound because every
+    // call is handed a region no other allocation overlaps.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+}
+
+impl Bump {
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        let end = match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => end,
+            _ => handle_alloc_error(layout),
+        };
+        self.used.set(end);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+
+    // Bytes currently handed out from the chunk.
+    pub fn allocated_bytes(&self) -> usize {
+        self.used.get()
+    }
+
+    // Rewinds the cursor so the chunk can be reused. Taking `&mut self` requires that
+    // no reference returned by `alloc` is still alive, since they all borrow `self`.
+    pub fn reset(&mut self) {
+        self.used.set(0);
+        self.generation += 1;
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// SECTION 2: VULNERABLE CODE
+
+// A handle to a value in the arena: its offset into the chunk. Unlike a reference
+// from `alloc`, a handle does not borrow the arena, so it does not block `reset`.
+#[derive(Debug)]
+pub struct AllocHandle<T> {
+    offset: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for AllocHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for AllocHandle<T> {}
+
+impl Bump {
+    pub fn alloc_handle<T>(&self, val: T) -> AllocHandle<T> {
+        let p = self.alloc_layout(Layout::new::<T>());
+        unsafe { ptr::write(p.as_ptr() as *mut T, val) };
+        AllocHandle {
+            offset: p.as_ptr() as usize - self.chunk.as_ptr() as usize,
+            _marker: PhantomData,
+        }
+    }
+
+    // Resolves a handle, rejecting offsets outside the bytes handed out so far. That
+    // check is not enough: after a `reset`, the handle's bytes can be handed out
+    // again, and a stale handle then resolves to whatever was allocated there.
+    pub fn get_handle<T>(&self, handle: AllocHandle<T>) -> Option<&T> {
+        if handle.offset + mem::size_of::<T>() > self.used.get() {
+            return None;
+        }
+        unsafe { Some(&*(self.chunk.as_ptr().add(handle.offset) as *const T)) }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT
+
+fn main() {
+    // 1. Setup a session token stored behind a handle.
+    let mut bump = Bump::new();
+    let token = bump.alloc_handle(*b"session=alice-42");
+    println!("Before reset: {:?}", bump.get_handle(token).map(|t| String::from_utf8_lossy(t)));
+
+    // 2. Trigger BUG: reset the arena. The handle holds no borrow, so this compiles.
+    bump.reset();
+
+    // 3. Allocate again. The new value is placed where the token lived.
+    bump.alloc_handle(*b"session=mallory!");
+
+    // 4. Resolve the old handle. It must be rejected or still see its own value.
+    let seen = bump.get_handle(token);
+    println!("After reset:  {:?}", seen.map(|t| String::from_utf8_lossy(t)));
+    assert!(
+        seen.is_none_or(|t| t == b"session=alice-42"),
+        "UAF CONFIRMED: a stale handle resolved to another allocation!"
+    );
+}
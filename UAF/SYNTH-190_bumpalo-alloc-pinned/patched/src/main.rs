@@ -0,0 +1,139 @@
+#![allow(clippy::new_without_default)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::Cell;
+use std::marker::PhantomPinned;
+use std::mem;
+use std::pin::Pin;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that carves every allocation out of a single chunk.
+// `used` is the bump cursor: the offset of the first byte no allocation owns yet.
+// An allocation never moves: its bytes stay where they are until the arena drops.
+pub struct Bump {
+    chunk: NonNull<u8>,
+    used: Cell<usize>,
+}
+
+const CHUNK_SIZE: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+impl Bump {
+    pub fn new() -> Self {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        let chunk = match NonNull::new(unsafe { std::alloc::alloc(layout) }) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        Bump {
+            chunk,
+            used: Cell::new(0),
+        }
+    }
+
+    // `alloc` takes `&self` but returns `&mut T`. That is only sound because every
+    // call is handed a region no other allocation overlaps.
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, val);
+            &mut *p
+        }
+    }
+}
+
+impl Bump {
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        assert!(layout.align() <= CHUNK_ALIGN);
+        let start = (self.used.get() + layout.align() - 1) & !(layout.align() - 1);
+        let end = match start.checked_add(layout.size()) {
+            Some(end) if end <= CHUNK_SIZE => end,
+            _ => handle_alloc_error(layout),
+        };
+        self.used.set(end);
+        unsafe { NonNull::new_unchecked(self.chunk.as_ptr().add(start)) }
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        let layout = Layout::from_size_align(CHUNK_SIZE, CHUNK_ALIGN).unwrap();
+        unsafe {
+            std::alloc::dealloc(self.chunk.as_ptr(), layout);
+        }
+    }
+}
+
+// A minimal self-referential value, standing in for an `async fn` state machine
+// that holds a pointer into its own storage. Once `init` has run it must never
+// move again, which is why it is `!Unpin`.
+pub struct SelfRef {
+    buf: [u8; 16],
+    ptr: *const u8,
+    _pin: PhantomPinned,
+}
+
+impl SelfRef {
+    pub fn new(buf: [u8; 16]) -> Self {
+        Self {
+            buf,
+            ptr: ptr::null(),
+            _pin: PhantomPinned,
+        }
+    }
+
+    // Points `ptr` at the value's own buffer.
+    pub fn init(self: Pin<&mut Self>) {
+        let this = unsafe { self.get_unchecked_mut() };
+        this.ptr = this.buf.as_ptr();
+    }
+
+    // Reads the buffer back through the self-pointer.
+    pub fn read(&self) -> [u8; 16] {
+        assert!(!self.ptr.is_null(), "SelfRef used before init");
+        unsafe { ptr::read(self.ptr as *const [u8; 16]) }
+    }
+}
+
+
+// SECTION 2: PATCHED CODE
+
+impl Bump {
+    // Moves `val` into the arena and pins it there.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_pinned<T>(&self, val: T) -> Pin<&mut T> {
+        // SAFETY: arena allocations never move, and the only way to reach the value
+        // is through the returned `Pin`, which hands out `&mut T` only for `T: Unpin`.
+        unsafe { Pin::new_unchecked(self.alloc(val)) }
+    }
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Setup a self-referential value, pinned in the arena slot it was allocated in.
+    let bump = Bump::new();
+    let mut pinned = bump.alloc_pinned(SelfRef::new(*b"pinned-state-ok!"));
+    pinned.as_mut().init();
+    println!("Read in place:              {:?}", String::from_utf8_lossy(&pinned.read()));
+    assert_eq!(&pinned.read(), b"pinned-state-ok!");
+
+    // 2. THIS NOW CAUSES A COMPILE-TIME ERROR.
+    // `alloc_pinned` returns `Pin<&mut SelfRef>`. Getting a `&mut SelfRef` out of it
+    // requires `SelfRef: Unpin`, which `PhantomPinned` rules out.
+    let moved = mem::replace(&mut *pinned, SelfRef::new(*b"AAAAAAAAAAAAAAAA")); // <-- COMPILE ERROR: cannot borrow data in dereference of `Pin<&mut SelfRef>` as mutable
+
+    // 3. This section is now unreachable due to the compile error above.
+    let seen = moved.read();
+    println!("Read through self-pointer:  {:?}", String::from_utf8_lossy(&seen));
+    assert_eq!(&seen, b"pinned-state-ok!");
+}
@@ -0,0 +1,255 @@
+#![allow(clippy::new_without_default, clippy::mut_from_ref)]
+// SECTION 1: MINIMAL DEPENDENCIES
+
+use std::alloc::Layout;
+use std::cell::RefCell;
+use std::mem::MaybeUninit;
+use std::ptr::{self, NonNull};
+
+// Helper functions
+fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+fn handle_alloc_error(layout: Layout) -> ! {
+    panic!("encountered allocation error: {:?}", layout)
+}
+
+// Simplified Bump allocator that correctly models arena-like deallocation on drop.
+pub struct Bump {
+    // Use the standard library's Vec for internal bookkeeping.
+    allocations: RefCell<std::vec::Vec<(NonNull<u8>, Layout)>>,
+}
+
+impl Bump {
+    pub fn new() -> Self {
+        Bump {
+            allocations: RefCell::new(std::vec::Vec::new()),
+        }
+    }
+
+    #[inline(always)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    pub fn alloc_with<F, T>(&self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        let layout = Layout::new::<T>();
+        unsafe {
+            let p = self.alloc_layout(layout).as_ptr() as *mut T;
+            ptr::write(p, f());
+            &mut *p
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        let non_null_ptr = match NonNull::new(ptr) {
+            Some(p) => p,
+            None => handle_alloc_error(layout),
+        };
+        self.allocations.borrow_mut().push((non_null_ptr, layout));
+        non_null_ptr
+    }
+}
+
+impl Drop for Bump {
+    fn drop(&mut self) {
+        for (ptr, layout) in self.allocations.get_mut().iter() {
+            unsafe {
+                std::alloc::dealloc(ptr.as_ptr(), *layout);
+            }
+        }
+    }
+}
+
+// Minimal definitions for Vec (as defined in the crate)
+pub struct RawVec<'a, T> {
+    ptr: NonNull<T>,
+    cap: usize,
+    a: &'a Bump,
+}
+
+impl<'a, T> RawVec<'a, T> {
+    pub fn new_in(a: &'a Bump) -> Self {
+        RawVec {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            a,
+        }
+    }
+
+    fn grow(&mut self, len: usize, additional: usize) {
+        let required_cap = len.checked_add(additional).unwrap_or_else(|| capacity_overflow());
+        let new_cap = required_cap.max(self.cap * 2).max(1);
+        let new_layout = Layout::array::<T>(new_cap).unwrap_or_else(|_| capacity_overflow());
+
+        let new_ptr = self.a.alloc_layout(new_layout);
+
+        if self.cap > 0 {
+            unsafe {
+                ptr::copy_nonoverlapping(self.ptr.as_ptr(), new_ptr.as_ptr() as *mut T, self.cap);
+            };
+        }
+        self.ptr = new_ptr.cast();
+        self.cap = new_cap;
+    }
+
+    pub fn reserve(&mut self, len: usize, additional: usize) {
+        if self.cap - len < additional {
+            self.grow(len, additional);
+        }
+    }
+
+    fn ptr(&self) -> *mut T { self.ptr.as_ptr() }
+
+    fn cap(&self) -> usize { self.cap }
+}
+
+pub struct Vec<'bump, T: 'bump> {
+    buf: RawVec<'bump, T>,
+    len: usize,
+}
+
+impl<'bump, T: 'bump> Vec<'bump, T> {
+    pub fn new_in(bump: &'bump Bump) -> Vec<'bump, T> {
+        Vec {
+            buf: RawVec::new_in(bump),
+            len: 0,
+        }
+    }
+
+    #[inline] pub fn len(&self) -> usize { self.len }
+    #[inline] pub fn is_empty(&self) -> bool { self.len == 0 }
+    #[inline] pub fn capacity(&self) -> usize { self.buf.cap() }
+    #[inline] pub fn as_mut_ptr(&mut self) -> *mut T { self.buf.ptr() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.buf.ptr(), self.len) }
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.buf.reserve(self.len, additional);
+    }
+
+    // Forces the length to `new_len`.
+    //
+    // The caller must ensure `new_len <= capacity()` and that every element in
+    // `old_len..new_len` has been initialized, e.g. through `spare_capacity_mut`.
+    #[inline]
+    #[allow(clippy::missing_safety_doc)]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        self.len = new_len;
+    }
+
+    // The uninitialized tail of the buffer, between `len` and the capacity. Writes
+    // here are committed with `set_len`.
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.buf.ptr().add(self.len) as *mut MaybeUninit<T>,
+                self.buf.cap() - self.len,
+            )
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.buf.cap() {
+            self.reserve(1);
+        }
+        unsafe {
+            let end = self.buf.ptr().add(self.len);
+            ptr::write(end, value);
+            self.len += 1;
+        }
+    }
+}
+
+impl<'bump, T: 'bump> Extend<T> for Vec<'bump, T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        for t in iter {
+            self.push(t);
+        }
+    }
+}
+
+// A one-byte flag. Only 0x00 and 0x01 are valid bit patterns, so producing a `Flag`
+// from any other byte is undefined behavior.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Flag {
+    Off = 0,
+    On = 1,
+}
+
+impl Flag {
+    pub fn decode(byte: u8) -> Option<Flag> {
+        match byte {
+            b'0' => Some(Flag::Off),
+            b'1' => Some(Flag::On),
+            _ => None,
+        }
+    }
+}
+
+// Views the flags as raw bytes, so the PoC can print what is really stored.
+fn raw_bytes(flags: &[Flag]) -> &[u8] {
+    unsafe { std::slice::from_raw_parts(flags.as_ptr() as *const u8, flags.len()) }
+}
+
+// SECTION 2: PATCHED CODE
+
+// Appends up to `count` flags decoded from `src`. Every slot is written through
+// `spare_capacity_mut` first, and the length is raised only by the number of
+// slots that were actually initialized.
+pub fn decode_flags(vec: &mut Vec<Flag>, src: &[u8], count: usize) {
+    vec.reserve(count);
+    let start = vec.len();
+    let mut written = 0;
+    for (slot, flag) in vec
+        .spare_capacity_mut()
+        .iter_mut()
+        .zip(src.iter().map_while(|&b| Flag::decode(b)).take(count))
+    {
+        slot.write(flag);
+        written += 1;
+    }
+    unsafe { vec.set_len(start + written) };
+}
+
+// SECTION 3: PROOF-OF-CONCEPT (DEMONSTRATES THE FIX)
+
+fn main() {
+    // 1. Leave stale bytes on the heap: an arena block of 64 bytes of 0x41 is freed.
+    {
+        let scratch = Bump::new();
+        scratch.alloc([0x41u8; 64]);
+    }
+
+    // 2. Setup a vector of flags. Its 64-flag buffer reuses the freed block.
+    let bump = Bump::new();
+    let mut flags = Vec::new_in(&bump);
+
+    // 3. The header claims 64 flags but only 4 are present.
+    decode_flags(&mut flags, b"1010", 64);
+
+    // 4. Read the vector. Only the 4 decoded flags are part of it.
+    let bytes = raw_bytes(flags.as_slice());
+    println!("Decoded {} flags, bytes: {:02x?}", flags.len(), bytes);
+
+    let invalid = bytes.iter().filter(|&&b| b > 1).count();
+    println!("Invalid Flag values: {}", invalid);
+    assert_eq!(invalid, 0);
+    assert_eq!(flags.as_slice(), &[Flag::On, Flag::Off, Flag::On, Flag::Off]);
+    println!("Verification successful: only initialized slots are exposed.");
+}